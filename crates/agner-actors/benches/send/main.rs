@@ -0,0 +1,202 @@
+//! Throughput of the message-sending paths of the [`System`].
+//!
+//! Run with `cargo bench -p agner-actors --bench send`.
+//!
+//! The `baseline/*` variants run the same load through [`RwLockTable`], a model of the former
+//! actor-table, for comparison.
+//!
+//! The baseline is not the former implementation: it only reproduces the locking of the former
+//! table, while the channels it hands out are the current
+//! [`BoundedActorChannel`](agner_actors::BoundedActorChannel)s, so that the message then goes
+//! through the current mailbox. The difference between a `baseline/*` variant and its
+//! counterpart is thus the cost of looking the channel up under the lock, not the difference
+//! between the two send paths as a whole; label the numbers accordingly when quoting them.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use agner_actors::{ActorID, Context, InboxOverflow, SpawnOpts, System};
+use futures::future;
+use tokio::sync::oneshot;
+
+mod rwlock_table;
+use rwlock_table::RwLockTable;
+
+const MESSAGES: usize = 400_000;
+const SENDERS: usize = 8;
+const ROUNDS: usize = 5;
+const BASELINE_TABLE_SIZE: usize = 64;
+
+#[derive(Debug, Clone)]
+struct Payload;
+
+async fn sink(
+    context: &mut Context<Payload>,
+    (expected, done_tx): (usize, oneshot::Sender<()>),
+) -> Infallible {
+    for _ in 0..expected {
+        let Payload = context.next_message().await;
+    }
+    let _ = done_tx.send(());
+    std::future::pending().await
+}
+
+async fn spawn_sinks(
+    system: &System,
+    count: usize,
+    expected: usize,
+) -> Vec<(ActorID, oneshot::Receiver<()>)> {
+    let mut sinks = vec![];
+    for _ in 0..count {
+        let (done_tx, done_rx) = oneshot::channel();
+        let spawn_opts = SpawnOpts::new().with_inbox_overflow(InboxOverflow::Block);
+        let actor = system.spawn(sink, (expected, done_tx), spawn_opts).await.unwrap();
+        sinks.push((actor, done_rx));
+    }
+    sinks
+}
+
+/// Register the channels to the actors in the baseline table, at the slots numbered in the order
+/// of `actor_ids`.
+async fn baseline_table(system: &System, actor_ids: &[ActorID]) -> Arc<RwLockTable> {
+    let table = RwLockTable::new(BASELINE_TABLE_SIZE);
+    for (slot_idx, actor_id) in actor_ids.iter().copied().enumerate() {
+        let chan = system.bounded_channel::<Payload>(actor_id).await.unwrap();
+        table.put(slot_idx, actor_id, chan).await;
+    }
+    Arc::new(table)
+}
+
+/// `senders` tasks sending [`MESSAGES`] in total, spread evenly over `actors` sinks.
+async fn send(
+    system: &System,
+    senders: usize,
+    actors: usize,
+    with_writer: bool,
+    baseline: bool,
+) -> Duration {
+    let sinks = spawn_sinks(system, actors, MESSAGES / actors).await;
+    let actor_ids = sinks.iter().map(|(actor, _)| *actor).collect::<Vec<_>>();
+    let table = if baseline { Some(baseline_table(system, &actor_ids).await) } else { None };
+
+    let writer = with_writer.then(|| {
+        let system = system.to_owned();
+        let actor_ids = actor_ids.to_owned();
+        let table = table.to_owned();
+        tokio::spawn(async move {
+            for i in 0usize.. {
+                let slot_idx = i % actor_ids.len();
+                match &table {
+                    Some(table) => table.put_data(slot_idx, actor_ids[slot_idx], i).await,
+                    None => system.put_data(actor_ids[slot_idx], i).await,
+                }
+                tokio::task::yield_now().await;
+            }
+        })
+    });
+
+    let t0 = Instant::now();
+    let send_tasks = (0..senders).map(|sender_idx| {
+        let system = system.to_owned();
+        let actor_ids = actor_ids.to_owned();
+        let table = table.to_owned();
+        tokio::spawn(async move {
+            for i in 0..MESSAGES / senders {
+                let slot_idx = (sender_idx + i) % actor_ids.len();
+                let to = actor_ids[slot_idx];
+                match &table {
+                    Some(table) => {
+                        let chan = table.channel::<Payload>(slot_idx, to).await.unwrap();
+                        chan.send(Payload).await.unwrap();
+                    },
+                    None => system.send(to, Payload).await,
+                }
+            }
+        })
+    });
+    future::join_all(send_tasks).await;
+    future::join_all(sinks.into_iter().map(|(_, done_rx)| done_rx)).await;
+    let elapsed = t0.elapsed();
+
+    if let Some(writer) = writer {
+        writer.abort();
+    }
+    for actor_id in actor_ids {
+        system.exit(actor_id, agner_actors::Exit::shutdown()).await;
+        system.wait(actor_id).await;
+    }
+
+    elapsed
+}
+
+/// `senders` tasks each looking up a channel to a single actor.
+async fn lookup(system: &System, senders: usize, baseline: bool) -> Duration {
+    let (actor, _done_rx) = spawn_sinks(system, 1, usize::MAX).await.pop().unwrap();
+    let table = if baseline { Some(baseline_table(system, &[actor]).await) } else { None };
+
+    let t0 = Instant::now();
+    let lookup_tasks = (0..senders).map(|_| {
+        let system = system.to_owned();
+        let table = table.to_owned();
+        tokio::spawn(async move {
+            for _ in 0..MESSAGES / senders {
+                match &table {
+                    Some(table) => table.channel::<Payload>(0, actor).await.unwrap(),
+                    None => system.bounded_channel::<Payload>(actor).await.unwrap(),
+                };
+            }
+        })
+    });
+    future::join_all(lookup_tasks).await;
+    let elapsed = t0.elapsed();
+
+    system.exit(actor, agner_actors::Exit::shutdown()).await;
+    system.wait(actor).await;
+
+    elapsed
+}
+
+fn report(name: &str, mut samples: Vec<Duration>) {
+    samples.sort();
+    let median = samples[samples.len() / 2];
+    let rate = MESSAGES as f64 / median.as_secs_f64();
+    println!("{:<40} median {:>10.2?} {:>12.0} ops/s", name, median, rate);
+}
+
+fn main() {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_time()
+        .build()
+        .expect("Failed to create tokio-runtime");
+
+    runtime.block_on(async {
+        let system = System::new(Default::default());
+
+        macro_rules! bench {
+            ($name: expr, $run: expr) => {{
+                let mut samples = vec![];
+                for _ in 0..ROUNDS {
+                    samples.push($run.await);
+                }
+                report($name, samples);
+            }};
+        }
+
+        for (prefix, baseline) in [("", false), ("baseline/", true)] {
+            let name = |name: &str| format!("{}{}", prefix, name);
+
+            bench!(&name("send/1-sender/1-actor"), send(&system, 1, 1, false, baseline));
+            bench!(&name("send/8-senders/1-actor"), send(&system, SENDERS, 1, false, baseline));
+            bench!(
+                &name("send/8-senders/8-actors"),
+                send(&system, SENDERS, SENDERS, false, baseline)
+            );
+            bench!(
+                &name("send/8-senders/8-actors/writer"),
+                send(&system, SENDERS, SENDERS, true, baseline)
+            );
+            bench!(&name("lookup/8-senders"), lookup(&system, SENDERS, baseline));
+        }
+    });
+}
@@ -0,0 +1,66 @@
+//! The baseline: a model of the actor-table as it was before the routes were split off the
+//! actor-entries.
+//!
+//! Each slot is a `RwLock` around the whole entry, so that looking up a channel contends with the
+//! writers of the entry (e.g. [`System::put_data`](agner_actors::System::put_data)).
+//!
+//! Only the table is modelled: the entries hold the current [`BoundedActorChannel`]s rather than
+//! the former `mpsc::UnboundedSender`s, so the baseline measures the locked lookup alone.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use agner_actors::{ActorID, BoundedActorChannel};
+use tokio::sync::RwLock;
+
+pub struct RwLockTable(Box<[RwLock<Entry>]>);
+
+#[derive(Default)]
+struct Entry {
+    actor_id: Option<ActorID>,
+    messages_tx: Option<Box<dyn Any + Send + Sync + 'static>>,
+    data: HashMap<TypeId, Box<dyn Any + Send + Sync + 'static>>,
+}
+
+impl RwLockTable {
+    pub fn new(size: usize) -> Self {
+        Self((0..size).map(|_| Default::default()).collect())
+    }
+
+    pub async fn put<M>(&self, slot_idx: usize, actor_id: ActorID, chan: BoundedActorChannel<M>)
+    where
+        M: Send + 'static,
+    {
+        let mut entry = self.0[slot_idx].write().await;
+        *entry = Entry {
+            actor_id: Some(actor_id),
+            messages_tx: Some(Box::new(chan)),
+            ..Default::default()
+        };
+    }
+
+    pub async fn channel<M>(
+        &self,
+        slot_idx: usize,
+        actor_id: ActorID,
+    ) -> Option<BoundedActorChannel<M>>
+    where
+        M: Send + 'static,
+    {
+        let entry = self.0[slot_idx].read().await;
+        if entry.actor_id != Some(actor_id) {
+            return None
+        }
+        entry.messages_tx.as_ref()?.downcast_ref::<BoundedActorChannel<M>>().cloned()
+    }
+
+    pub async fn put_data<D>(&self, slot_idx: usize, actor_id: ActorID, data: D)
+    where
+        D: Any + Send + Sync + 'static,
+    {
+        let mut entry = self.0[slot_idx].write().await;
+        if entry.actor_id == Some(actor_id) {
+            entry.data.insert(TypeId::of::<D>(), Box::new(data));
+        }
+    }
+}
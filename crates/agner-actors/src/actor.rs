@@ -30,3 +30,45 @@ where
         self(context, args)
     }
 }
+
+/// A marker trait for the behaviour function of an actor, that is not required to be `Send` (see
+/// [`System::spawn_local`](crate::system::System::spawn_local)).
+///
+/// An async-function suitable as an [`Actor`] is also a `LocalActor`.
+pub trait LocalActor<'a, A, M>: 'static {
+    type Out: Into<Exit>;
+    type Fut: Future<Output = Self::Out> + 'a;
+
+    fn run(self, context: &'a mut Context<M>, args: A) -> Self::Fut;
+}
+
+impl<'a, A, M, F, Fut, Out> LocalActor<'a, A, M> for F
+where
+    M: 'a,
+    F: FnOnce(&'a mut Context<M>, A) -> Fut,
+    Fut: Future<Output = Out> + 'a,
+    Out: Into<Exit>,
+    F: 'static,
+{
+    type Out = Out;
+    type Fut = Fut;
+
+    fn run(self, context: &'a mut Context<M>, args: A) -> Self::Fut {
+        self(context, args)
+    }
+}
+
+/// Runs an [`Actor`] as a [`LocalActor`], so that both kinds of actors share the same runner.
+pub(crate) struct AsLocal<B>(pub B);
+
+impl<'a, A, M, B> LocalActor<'a, A, M> for AsLocal<B>
+where
+    B: Actor<'a, A, M>,
+{
+    type Out = B::Out;
+    type Fut = B::Fut;
+
+    fn run(self, context: &'a mut Context<M>, args: A) -> Self::Fut {
+        self.0.run(context, args)
+    }
+}
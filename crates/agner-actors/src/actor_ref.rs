@@ -0,0 +1,142 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::actor_id::ActorID;
+use crate::exit::Exit;
+use crate::mailbox::MailboxTx;
+use crate::system::{CallError, ReplyTo, SendError, SystemWeakRef};
+
+/// A handle to an actor accepting the messages of type `M`.
+///
+/// Unlike [`ActorID`], an `ActorRef<M>` carries the type of the messages the actor accepts, so
+/// that sending a message of a wrong type is rejected at compile-time. It holds a channel to the
+/// actor, so that no lookup is performed when a message is sent.
+///
+/// An `ActorRef<M>` is obtained:
+/// - upon spawning an actor with [`System::spawn_typed`](crate::system::System::spawn_typed);
+/// - by the actor itself with [`Context::myself`](crate::context::Context::myself);
+/// - from an [`ActorID`] via [`System::actor_ref`](crate::system::System::actor_ref).
+///
+/// Under the `serde` feature, an `ActorRef<M>` is serialized as its [`ActorID`].
+pub struct ActorRef<M> {
+    actor_id: ActorID,
+    messages_tx: MailboxTx<M>,
+    system: SystemWeakRef,
+}
+
+impl<M> ActorRef<M> {
+    pub(crate) fn new(actor_id: ActorID, messages_tx: MailboxTx<M>, system: SystemWeakRef) -> Self {
+        Self { actor_id, messages_tx, system }
+    }
+
+    /// The [`ActorID`] of the referred actor.
+    pub fn actor_id(&self) -> ActorID {
+        self.actor_id
+    }
+
+    pub(crate) fn messages_tx(&self) -> &MailboxTx<M> {
+        &self.messages_tx
+    }
+}
+
+impl<M> ActorRef<M>
+where
+    M: Send + 'static,
+{
+    /// Send a single message to the actor, respecting its
+    /// [`InboxOverflow`](crate::spawn_opts::InboxOverflow) policy.
+    ///
+    /// The message is given back should the actor be gone or its inbox be full.
+    pub async fn send(&self, message: M) -> Result<(), SendError<M>> {
+        self.messages_tx.send(message).await
+    }
+
+    /// Send a single message to the actor without waiting.
+    pub fn try_send(&self, message: M) -> Result<(), SendError<M>> {
+        self.messages_tx.try_send(message)
+    }
+
+    /// Send a single message to the actor via its urgent lane (see
+    /// [`System::send_urgent`](crate::system::System::send_urgent)).
+    pub fn send_urgent(&self, message: M) -> Result<(), SendError<M>> {
+        self.messages_tx.send_urgent(message)
+    }
+
+    /// Send a request to the actor and wait for the reply (see
+    /// [`System::call`](crate::system::System::call)).
+    pub async fn call<R, F>(&self, make_request: F, timeout: Duration) -> Result<R, CallError>
+    where
+        R: Send + 'static,
+        F: FnOnce(ReplyTo<R>) -> M,
+    {
+        let system = self.system.rc_upgrade().ok_or(CallError::NoActor)?;
+        system.call(self.actor_id, make_request, timeout).await
+    }
+
+    /// Link the actor to another actor (see [`System::link`](crate::system::System::link)).
+    pub async fn link(&self, to: impl Into<ActorID>) {
+        if let Some(system) = self.system.rc_upgrade() {
+            system.link(self.actor_id, to.into()).await
+        }
+    }
+
+    /// Wait for the actor to terminate (see [`System::wait`](crate::system::System::wait)).
+    pub async fn wait(&self) -> Exit {
+        if let Some(system) = self.system.rc_upgrade() {
+            system.wait(self.actor_id).await
+        } else {
+            Exit::no_actor()
+        }
+    }
+}
+
+impl<M> From<ActorRef<M>> for ActorID {
+    fn from(actor_ref: ActorRef<M>) -> Self {
+        actor_ref.actor_id
+    }
+}
+
+impl<M> From<&ActorRef<M>> for ActorID {
+    fn from(actor_ref: &ActorRef<M>) -> Self {
+        actor_ref.actor_id
+    }
+}
+
+impl<M> Clone for ActorRef<M> {
+    fn clone(&self) -> Self {
+        Self {
+            actor_id: self.actor_id,
+            messages_tx: self.messages_tx.to_owned(),
+            system: self.system.to_owned(),
+        }
+    }
+}
+
+impl<M> PartialEq for ActorRef<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.actor_id == other.actor_id
+    }
+}
+impl<M> Eq for ActorRef<M> {}
+
+impl<M> fmt::Display for ActorRef<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.actor_id, f)
+    }
+}
+
+impl<M> fmt::Debug for ActorRef<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ActorRef").field(&self.actor_id).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<M> serde::Serialize for ActorRef<M> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.actor_id.serialize(serializer)
+    }
+}
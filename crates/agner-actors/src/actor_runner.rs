@@ -1,65 +1,95 @@
+use std::collections::VecDeque;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Instant;
 
 use agner_utils::std_error_pp::StdErrorPP;
 use futures::stream::FuturesUnordered;
 use futures::StreamExt;
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, watch};
 use tracing::Instrument;
 
-use crate::actor::Actor;
+use crate::actor::LocalActor;
 use crate::actor_id::ActorID;
+use crate::actor_ref::ActorRef;
 use crate::context::{Context, Signal};
-use crate::exit::{BackendFailure, Exit};
+use crate::dead_letter::DeadLetter;
+use crate::exit::{self, BackendFailure, Exit};
 use crate::exit_handler::ExitHandler;
-use crate::spawn_opts::SpawnOpts;
-use crate::system::SystemWeakRef;
+use crate::mailbox::{MailboxRx, MailboxTx, UrgentRx};
+use crate::spawn_opts::{InboxOverflow, SignalOverflow, SpawnOpts};
+use crate::system::{RefSeqs, SystemWeakRef};
+use crate::system_config::SlowPollWatchdog;
 
 pub(crate) mod call_msg;
 mod impl_debug;
 pub(crate) mod pipe;
+mod stats;
 pub(crate) mod sys_msg;
+mod timers;
 mod watches;
 
-use call_msg::CallMsg;
+use call_msg::{CallMsg, TerminateHook};
+use stats::{BackendStats, PollStats, PollTimed, Watchdog};
 use sys_msg::SysMsg;
+use timers::Timers;
 use watches::Watches;
 
 use self::pipe::{PipeRx, PipeTx};
-pub use self::sys_msg::ActorInfo;
+pub use self::sys_msg::{ActorInfo, ActorStats};
 
 pub(crate) struct ActorRunner<Message> {
     pub actor_id: ActorID,
     pub system_opt: SystemWeakRef,
-    pub messages_rx: mpsc::UnboundedReceiver<Message>,
+    pub messages_rx: MailboxRx<Message>,
+    pub messages_tx: MailboxTx<Message>,
+    pub urgent_rx: UrgentRx<Message>,
     pub sys_msg_rx: mpsc::UnboundedReceiver<SysMsg>,
     pub sys_msg_tx: mpsc::UnboundedSender<SysMsg>,
+    pub exited_tx: watch::Sender<Option<Exit>>,
     pub exit_handler: Arc<dyn ExitHandler>,
     pub spawn_opts: SpawnOpts,
+    pub slow_poll_watchdog: Option<SlowPollWatchdog>,
+    pub ref_seqs: Arc<RefSeqs>,
 }
 
 impl<Message> ActorRunner<Message>
 where
-    Message: Unpin,
+    Message: Unpin + Send + 'static,
 {
-    #[tracing::instrument(skip_all, fields(
-        actor_id = display(self.actor_id),
-        behaviour = std::any::type_name::<Behaviour>(),
-        msg_type = std::any::type_name::<Message>(),
-    ))]
-    pub async fn run<Behaviour, Args>(self, behaviour: Behaviour, args: Args)
+    /// Run the actor.
+    ///
+    /// The backend of the actor is set up before the returned future is first polled: should the
+    /// future be dropped before it completes (e.g. along with the
+    /// [`LocalSet`](tokio::task::LocalSet) it was spawned onto), the actor exits with
+    /// [`BackendFailure::Dropped`].
+    pub fn run<Behaviour, Args>(self, behaviour: Behaviour, args: Args) -> impl Future<Output = ()>
     where
-        for<'a> Behaviour: Actor<'a, Args, Message>,
+        for<'a> Behaviour: LocalActor<'a, Args, Message>,
     {
+        let span = tracing::span!(
+            tracing::Level::TRACE,
+            "run",
+            actor_id = display(self.actor_id),
+            behaviour = std::any::type_name::<Behaviour>(),
+            msg_type = std::any::type_name::<Message>(),
+        );
+        let _entered = span.enter();
+
         let Self {
             actor_id,
             system_opt,
             messages_rx,
+            messages_tx,
+            urgent_rx,
             sys_msg_rx,
             sys_msg_tx,
+            exited_tx,
             exit_handler,
             mut spawn_opts,
+            slow_poll_watchdog,
+            ref_seqs,
         } = self;
 
         tracing::trace!(
@@ -70,40 +100,67 @@ where
         );
 
         let (inbox_w, inbox_r) = pipe::new::<Message>(spawn_opts.msg_inbox_size());
+        let inbox_r = inbox_r.giving_back(messages_rx.room().cloned());
+        // the urgent lane is unbounded, so that the behaviour never finds it empty while there are
+        // urgent messages waiting for room in it.
+        let (urgent_w, urgent_r) = pipe::new::<Message>(usize::MAX);
         let (signals_w, signals_r) = pipe::new::<Signal>(spawn_opts.sig_inbox_size());
         let (calls_w, calls_r) = pipe::new::<CallMsg<Message>>(1);
-        let mut context =
-            Context::new(actor_id, system_opt.to_owned(), inbox_r, signals_r, calls_w)
-                .with_data(spawn_opts.take_data());
-
-        let behaviour_running = async move {
-            let exit_reason = behaviour
-                .run(&mut context, args)
-                .instrument(tracing::span!(tracing::Level::TRACE, "<behaviour as Actor>::run"))
-                .await
-                .into();
+        let myself = ActorRef::new(actor_id, messages_tx, system_opt.to_owned());
+        let mut context = Context::new(
+            myself,
+            system_opt.to_owned(),
+            ref_seqs,
+            inbox_r,
+            urgent_r,
+            signals_r,
+            calls_w,
+        )
+        .with_data(spawn_opts.take_data());
+
+        let poll_stats = Arc::new(PollStats::default());
+        let watchdog = slow_poll_watchdog.map(|config| {
+            Arc::new(Watchdog::new(actor_id, std::any::type_name::<Behaviour>(), config))
+        });
+        let behaviour_running = PollTimed::new(async move {
+            let exit_reason = behaviour_exit(behaviour.run(&mut context, args)).await;
             context
-                .exit(exit_reason.clone())
+                .exit(exit_reason)
                 .instrument(tracing::span!(tracing::Level::TRACE, "Context::exit"))
                 .await;
             unreachable!()
-        };
+        })
+        .with_stats(poll_stats.to_owned())
+        .with_watchdog(watchdog.to_owned(), "behaviour");
 
         let mut actor_backend = Backend {
             actor_id,
-            system_opt: system_opt.to_owned(),
+            system_opt,
             sys_msg_rx,
             sys_msg_tx,
+            exited_tx,
             messages_rx,
             inbox_w,
+            inbox_overflow: spawn_opts.inbox_overflow(),
+            inbox_pending: Default::default(),
+            urgent_rx,
+            urgent_w,
             signals_w,
+            signal_overflow: spawn_opts.signal_overflow(),
+            signals_spilled: Default::default(),
+            signals_spilled_total: 0,
             calls_r,
             watches: Default::default(),
-            tasks: FuturesUnordered::<
-                Pin<Box<dyn Future<Output = Option<Message>> + Send + Sync + 'static>>,
-            >::new(),
+            timers: Default::default(),
+            tasks: FuturesUnordered::new(),
+            terminate_hook: None,
+            stats: Default::default(),
+            poll_stats,
+            watchdog,
 
             exit_handler,
+            exit_notified: false,
+            entry_terminated: false,
 
             actor_type_info: (
                 std::any::type_name::<Behaviour>(),
@@ -111,51 +168,81 @@ where
                 std::any::type_name::<Message>(),
             ),
         };
+        let links = spawn_opts.links().collect::<Vec<_>>();
 
-        for link_to in spawn_opts.links() {
-            actor_backend.do_link(link_to).await;
-        }
-
-        let actor_backend_running = actor_backend.run_actor_backend();
-
-        tracing::trace!("running...");
-        let exit_reason = tokio::select! {
-            biased;
+        async move {
+            for link_to in links {
+                actor_backend.do_link(link_to).await;
+            }
 
-            exit_reason = actor_backend_running => exit_reason,
-            _ = behaviour_running => unreachable!("Future<Output = Infallible> has returned"),
-        };
-        tracing::trace!("exited: {}", exit_reason.pp());
+            tracing::trace!("running...");
+            tokio::select! {
+                biased;
 
-        if let Some(system) = system_opt.rc_upgrade() {
-            tracing::trace!("cleaning up actor-entry...");
-            system.actor_entry_terminate(actor_id, exit_reason).await;
+                () = actor_backend.run_actor_backend() => (),
+                _ = behaviour_running => unreachable!("Future<Output = Infallible> has returned"),
+            };
         }
+        .instrument(span.to_owned())
+    }
+}
+
+/// Run the behaviour to completion, turning its outcome (or its panic) into the exit reason.
+async fn behaviour_exit<Out>(behaviour_run: impl Future<Output = Out>) -> Exit
+where
+    Out: Into<Exit>,
+{
+    let behaviour_run = behaviour_run
+        .instrument(tracing::span!(tracing::Level::TRACE, "<behaviour as Actor>::run"));
+    match exit::panic::catch_panic(behaviour_run).await {
+        Ok(out) => out.into(),
+        Err(exit_reason) => exit_reason,
     }
 }
 
-struct Backend<Message> {
+type Job<Message> =
+    Pin<Box<dyn Future<Output = Result<Option<Message>, Exit>> + Send + Sync + 'static>>;
+
+struct Backend<Message>
+where
+    Message: Unpin + Send + 'static,
+{
     actor_id: ActorID,
     system_opt: SystemWeakRef,
     sys_msg_rx: mpsc::UnboundedReceiver<SysMsg>,
     sys_msg_tx: mpsc::UnboundedSender<SysMsg>,
-    messages_rx: mpsc::UnboundedReceiver<Message>,
+    exited_tx: watch::Sender<Option<Exit>>,
+    messages_rx: MailboxRx<Message>,
     inbox_w: PipeTx<Message>,
+    inbox_overflow: InboxOverflow,
+    inbox_pending: VecDeque<Message>,
+    urgent_rx: UrgentRx<Message>,
+    urgent_w: PipeTx<Message>,
     signals_w: PipeTx<Signal>,
+    signal_overflow: SignalOverflow,
+    signals_spilled: VecDeque<Signal>,
+    signals_spilled_total: usize,
     calls_r: PipeRx<CallMsg<Message>>,
     watches: Watches,
-    tasks: FuturesUnordered<Pin<Box<dyn Future<Output = Option<Message>> + Send + Sync + 'static>>>,
+    timers: Timers<Message>,
+    tasks: FuturesUnordered<Job<Message>>,
+    terminate_hook: Option<TerminateHook>,
+    stats: BackendStats,
+    poll_stats: Arc<PollStats>,
+    watchdog: Option<Arc<Watchdog>>,
     exit_handler: Arc<dyn ExitHandler>,
+    exit_notified: bool,
+    entry_terminated: bool,
 
     actor_type_info: (&'static str, &'static str, &'static str),
 }
 
 impl<Message> Backend<Message>
 where
-    Message: Unpin,
+    Message: Unpin + Send + 'static,
 {
     #[tracing::instrument(skip_all)]
-    async fn run_actor_backend(mut self) -> Exit {
+    async fn run_actor_backend(&mut self) {
         tracing::trace!("running actor-backend");
 
         let exit_reason = loop {
@@ -166,42 +253,108 @@ where
                     self.tasks.next().await
                 }
             };
+            let timer_deadline = self.timers.next_deadline();
+            let timer_expired = async move {
+                if let Some(deadline) = timer_deadline {
+                    tokio::time::sleep_until(deadline).await
+                } else {
+                    std::future::pending().await
+                }
+            };
+            let watchdog = self.watchdog.to_owned();
+            let watchdog_tripped = async move {
+                if let Some(watchdog) = watchdog {
+                    watchdog.tripped().await
+                } else {
+                    std::future::pending().await
+                }
+            };
 
             if let Err(exit_reason) = tokio::select! {
                 biased;
 
                 sys_msg_recv = self.sys_msg_rx.recv() =>
                     self.handle_sys_msg(sys_msg_recv).await,
+                exit_reason = watchdog_tripped => Err(exit_reason),
+                () = self.signals_w.ready(), if !self.signals_spilled.is_empty() =>
+                    self.handle_signals_ready().await,
                 call_msg = self.calls_r.recv() =>
                     self.handle_call_msg(call_msg).await,
-                message_recv = self.messages_rx.recv() =>
+                urgent_recv = self.urgent_rx.recv() =>
+                    self.handle_urgent_recv(urgent_recv).await,
+                message_recv = self.messages_rx.recv(), if self.inbox_pending.is_empty() =>
                     self.handle_message_recv(message_recv).await,
+                () = self.inbox_w.ready(), if !self.inbox_pending.is_empty() =>
+                    self.handle_inbox_ready().await,
                 task_ready = task_next =>
-                    if let Some(message) = task_ready.flatten() {
-                        self.handle_message_recv(Some(message)).await
-                    } else {
-                        Ok(())
+                    match task_ready {
+                        Some(Ok(Some(message))) => {
+                            self.stats.jobs_completed += 1;
+                            if let Some(room) = self.messages_rx.room() {
+                                room.take_by_actor();
+                            }
+                            self.handle_message_recv(Some(message)).await
+                        },
+                        Some(Ok(None)) => {
+                            self.stats.jobs_completed += 1;
+                            Ok(())
+                        },
+                        Some(Err(exit_reason)) => Err(exit_reason),
+                        None => Ok(()),
                     },
+                () = timer_expired =>
+                    self.handle_timer_expired().await,
             } {
                 break exit_reason
             }
         };
         tracing::trace!("exiting: {}", exit_reason.pp());
 
-        self.sys_msg_rx.close();
-        self.messages_rx.close();
+        // published before the messages (and the `ReplyTo`s in them) are dropped, so that the
+        // pending calls fail with the callee's exit reason.
+        self.exited_tx.send_replace(Some(exit_reason.to_owned()));
 
-        self.exit_handler.on_actor_exit(self.actor_id, exit_reason.to_owned());
+        self.sys_msg_rx.close();
+        self.messages_rx.close(&mut self.urgent_rx);
 
-        self.notify_linked_actors(exit_reason.to_owned()).await;
+        self.run_terminate_hook(&exit_reason).await;
 
-        while let Some(sys_msg) = self.sys_msg_rx.recv().await {
-            self.handle_sys_msg_on_shutdown(sys_msg, exit_reason.to_owned()).await
+        for report_to in self.notify_exit(exit_reason.to_owned()) {
+            let _ = self.handle_sys_msg_get_info(report_to).await;
         }
 
         tracing::trace!("exited");
 
-        exit_reason
+        if let Some(system) = self.system_opt.rc_upgrade() {
+            tracing::trace!("cleaning up actor-entry...");
+            system.actor_entry_terminate(self.actor_id, exit_reason).await;
+        }
+        self.entry_terminated = true;
+    }
+
+    /// Notify the exit-handler, the linked and the monitoring actors, and the actors that have
+    /// tried to link to or to monitor this one while it was exiting.
+    ///
+    /// Synchronous (the sys-msg channels are unbounded), so that [`Drop::drop`] notifies everyone
+    /// just as the normal exit does. The requests for the [`ActorInfo`] that have arrived while
+    /// exiting are returned to the caller: collecting the info takes waiting.
+    #[tracing::instrument(skip_all)]
+    fn notify_exit(&mut self, exit_reason: Exit) -> Vec<oneshot::Sender<ActorInfo>> {
+        if std::mem::replace(&mut self.exit_notified, true) {
+            return vec![]
+        }
+
+        self.exit_handler.on_actor_exit(self.actor_id, exit_reason.to_owned());
+
+        self.notify_linked_actors(exit_reason.to_owned());
+        self.notify_monitoring_actors(exit_reason.to_owned());
+        self.demonitor_monitored_actors();
+
+        let mut info_requests = vec![];
+        while let Ok(sys_msg) = self.sys_msg_rx.try_recv() {
+            info_requests.extend(self.handle_sys_msg_on_shutdown(sys_msg, exit_reason.to_owned()));
+        }
+        info_requests
     }
 
     #[tracing::instrument(skip_all)]
@@ -214,27 +367,40 @@ where
                 self.handle_sys_msg_sig_exit(terminated, exit_reason).await,
             SysMsg::Link(link_to) => self.handle_sys_msg_link(link_to).await,
             SysMsg::Unlink(unlink_from) => self.handle_sys_msg_unlink(unlink_from).await,
+            SysMsg::Monitor(monitor_ref, watcher) =>
+                self.handle_sys_msg_monitor(monitor_ref, watcher).await,
+            SysMsg::Demonitor(monitor_ref) => self.handle_sys_msg_demonitor(monitor_ref).await,
+            SysMsg::Down(monitor_ref, terminated, exit_reason) =>
+                self.handle_sys_msg_down(monitor_ref, terminated, exit_reason).await,
             SysMsg::GetInfo(report_to) => self.handle_sys_msg_get_info(report_to).await,
         }
     }
 
     #[tracing::instrument(skip_all)]
-    async fn handle_sys_msg_on_shutdown(&mut self, sys_msg: SysMsg, exit_reason: Exit) {
+    fn handle_sys_msg_on_shutdown(
+        &mut self,
+        sys_msg: SysMsg,
+        exit_reason: Exit,
+    ) -> Option<oneshot::Sender<ActorInfo>> {
         tracing::trace!("received sys-msg when shutting down: {:?}", sys_msg);
         match sys_msg {
             SysMsg::Link(linked) =>
                 if exit_reason.is_normal() {
-                    self.send_sys_msg(linked, SysMsg::Unlink(self.actor_id)).await;
+                    self.send_sys_msg(linked, SysMsg::Unlink(self.actor_id));
                 } else {
-                    self.send_sys_msg(linked, SysMsg::SigExit(self.actor_id, exit_reason)).await;
+                    self.send_sys_msg(linked, SysMsg::SigExit(self.actor_id, exit_reason));
                 },
-
-            SysMsg::GetInfo(report_to) => {
-                let _ = self.handle_sys_msg_get_info(report_to).await;
+            SysMsg::Monitor(monitor_ref, watcher) => {
+                self.send_sys_msg(watcher, SysMsg::Down(monitor_ref, self.actor_id, exit_reason));
             },
+
+            SysMsg::GetInfo(report_to) => return Some(report_to),
             SysMsg::Unlink { .. } => (),
             SysMsg::SigExit { .. } => (),
+            SysMsg::Demonitor { .. } => (),
+            SysMsg::Down { .. } => (),
         }
+        None
     }
 
     #[tracing::instrument(skip_all)]
@@ -243,8 +409,43 @@ where
             CallMsg::Exit(exit_reason) => Err(exit_reason),
             CallMsg::Link(link_to) => self.handle_call_link(link_to).await,
             CallMsg::Unlink(unlink_from) => self.handle_call_unlink(unlink_from).await,
+            CallMsg::Monitor(monitor_ref, actor_id) =>
+                self.handle_call_monitor(monitor_ref, actor_id).await,
+            CallMsg::Demonitor(monitor_ref) => self.handle_call_demonitor(monitor_ref).await,
             CallMsg::TrapExit(trap_exit) => self.handle_set_trap_exit(trap_exit),
+            CallMsg::SetTimer(timer_ref, deadline, message) =>
+                self.handle_call_set_timer(timer_ref, deadline, *message),
+            CallMsg::CancelTimer(timer_ref, report_to) =>
+                self.handle_call_cancel_timer(timer_ref, report_to),
             CallMsg::SpawnJob(fut) => self.handle_spawn_job(fut),
+            CallMsg::OnTerminate(hook) => self.handle_call_on_terminate(hook),
+        }
+    }
+
+    fn handle_call_on_terminate(&mut self, hook: TerminateHook) -> Result<(), Exit> {
+        self.terminate_hook = Some(hook);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn run_terminate_hook(&mut self, exit_reason: &Exit) {
+        let Some(hook) = self.terminate_hook.take() else { return };
+        if exit_reason.is_kill() {
+            tracing::trace!("killed, skipping the terminate-hook");
+            return
+        }
+        let Some(timeout) =
+            self.system_opt.rc_upgrade().map(|s| s.config().actor_termination_timeout)
+        else {
+            return
+        };
+
+        tracing::trace!("running the terminate-hook [timeout: {:?}]", timeout);
+        let hook_running = exit::panic::catch_panic(hook(exit_reason.to_owned()));
+        match tokio::time::timeout(timeout, hook_running).await {
+            Ok(Ok(())) => tracing::trace!("terminate-hook complete"),
+            Ok(Err(hook_failure)) => tracing::warn!("terminate-hook failed: {}", hook_failure.pp()),
+            Err(_elapsed) => tracing::warn!("terminate-hook timed out [timeout: {:?}]", timeout),
         }
     }
 
@@ -252,23 +453,80 @@ where
         &mut self,
         fut: Pin<Box<dyn Future<Output = Option<Message>> + Send + Sync + 'static>>,
     ) -> Result<(), Exit> {
-        self.tasks.push(fut);
+        let fut = PollTimed::new(fut).with_watchdog(self.watchdog.to_owned(), "job");
+        self.tasks.push(Box::pin(exit::panic::catch_panic(fut)));
+        self.stats.jobs_spawned += 1;
         Ok(())
     }
 
     #[tracing::instrument(skip_all)]
     async fn handle_message_recv(&mut self, message_recv: Option<Message>) -> Result<(), Exit> {
         let message = message_recv.ok_or(BackendFailure::RxClosed("messages"))?;
-        self.inbox_w
+        self.stats.messages_received += 1;
+        self.stats.last_message_at = Some(Instant::now());
+
+        if !self.inbox_pending.is_empty() {
+            self.inbox_pending.push_back(message);
+            return Ok(())
+        }
+
+        match self.inbox_overflow {
+            InboxOverflow::Crash => self
+                .inbox_w
+                .send(message)
+                .await
+                .map_err(|_rejected| BackendFailure::InboxFull("messages"))?,
+            InboxOverflow::DropNewest =>
+                if let Err(dropped) = self.inbox_w.send(message).await {
+                    tracing::trace!("inbox full, dropping the newest message");
+                    self.dead_letter(dropped);
+                },
+            InboxOverflow::DropOldest =>
+                if let Some(dropped) = self.inbox_w.send_evicting(message).await {
+                    tracing::trace!("inbox full, dropped the oldest message");
+                    self.dead_letter(dropped);
+                },
+            InboxOverflow::Reject | InboxOverflow::Block =>
+                if let Err(rejected) = self.inbox_w.send(message).await {
+                    tracing::trace!("inbox full, holding the message until there is room");
+                    self.inbox_pending.push_back(rejected);
+                },
+        }
+        Ok(())
+    }
+
+    fn dead_letter(&self, dropped: Message) {
+        if let Some(system) = self.system_opt.rc_upgrade() {
+            let dead_letter = DeadLetter::inbox_overflow(self.actor_id, dropped);
+            system.config().dead_letter_handler.on_dead_letter(dead_letter);
+        }
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn handle_inbox_ready(&mut self) -> Result<(), Exit> {
+        while let Some(message) = self.inbox_pending.pop_front() {
+            if let Err(rejected) = self.inbox_w.send(message).await {
+                self.inbox_pending.push_front(rejected);
+                break
+            }
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn handle_urgent_recv(&mut self, urgent_recv: Option<Message>) -> Result<(), Exit> {
+        let message = urgent_recv.ok_or(BackendFailure::RxClosed("urgent-messages"))?;
+        self.stats.urgent_received += 1;
+        self.urgent_w
             .send(message)
             .await
-            .map_err(|_rejected| BackendFailure::InboxFull("messages"))?;
+            .map_err(|_rejected| BackendFailure::InboxFull("urgent-messages"))?;
         Ok(())
     }
 
     #[tracing::instrument(skip_all)]
     async fn handle_sys_msg_get_info(
-        &self,
+        &mut self,
         report_to: oneshot::Sender<ActorInfo>,
     ) -> Result<(), Exit> {
         let info = ActorInfo {
@@ -279,13 +537,62 @@ where
             message_type: self.actor_type_info.2,
 
             m_queue_len: self.inbox_w.len().await,
+            u_queue_len: self.urgent_w.len().await,
             s_queue_len: self.signals_w.len().await,
+            s_spilled_len: self.signals_spilled.len(),
+            s_spilled_total: self.signals_spilled_total,
             c_queue_len: self.calls_r.len().await,
             tasks_count: self.tasks.len(),
+            timers_count: self.timers.len(),
             trap_exit: self.watches.trap_exit,
             links: self.watches.links.iter().copied().collect(),
+            monitors: self.watches.monitors.iter().map(|(r, id)| (*r, *id)).collect(),
+            monitored_by: self.watches.monitored_by.iter().map(|(r, id)| (*r, *id)).collect(),
+            stats: self.stats.report(
+                &self.poll_stats,
+                self.watchdog.as_deref(),
+                self.inbox_w.received_count().await as u64,
+                self.urgent_w.received_count().await as u64,
+            ),
         };
         let _ = report_to.send(info);
         Ok(())
     }
 }
+
+impl<Message> Drop for Backend<Message>
+where
+    Message: Unpin + Send + 'static,
+{
+    /// Should the backend be dropped before the actor has exited (e.g. the `LocalSet` of a local
+    /// actor is dropped, or is never driven), the actor exits with [`BackendFailure::Dropped`]:
+    /// the terminate-hook is skipped, but the linked and the monitoring actors are notified, and
+    /// the actor-entry is terminated.
+    ///
+    /// This also happens to every actor still running when the runtime shuts down. Hence nothing
+    /// here waits: the notifications go through the unbounded sys-msg channels, and the entry is
+    /// terminated right away unless it is locked at the moment (then a task is spawned to do it).
+    fn drop(&mut self) {
+        if self.entry_terminated {
+            return
+        }
+        let exit_reason = self
+            .exited_tx
+            .borrow()
+            .to_owned()
+            .unwrap_or_else(|| BackendFailure::Dropped.into());
+        tracing::trace!("[{}] dropped before exiting: {}", self.actor_id, exit_reason.pp());
+
+        self.exited_tx.send_replace(Some(exit_reason.to_owned()));
+
+        self.sys_msg_rx.close();
+        self.messages_rx.close(&mut self.urgent_rx);
+
+        // the requests for the info are dropped unanswered: the actor is gone
+        let _ = self.notify_exit(exit_reason.to_owned());
+
+        if let Some(system) = self.system_opt.rc_upgrade() {
+            system.actor_entry_terminate_now(self.actor_id, exit_reason);
+        }
+    }
+}
@@ -2,15 +2,28 @@ use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
 
+use tokio::sync::oneshot;
+use tokio::time::Instant;
+
 use crate::actor_id::ActorID;
 use crate::exit::Exit;
+use crate::monitor::MonitorRef;
+use crate::timer::TimerRef;
+
+pub(crate) type TerminateHook =
+    Box<dyn FnOnce(Exit) -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> + Send + Sync>;
 
 pub enum CallMsg<M> {
     Exit(Exit),
     Link(ActorID),
     Unlink(ActorID),
+    Monitor(MonitorRef, ActorID),
+    Demonitor(MonitorRef),
     TrapExit(bool),
+    SetTimer(TimerRef, Instant, Box<M>),
+    CancelTimer(TimerRef, oneshot::Sender<Option<Instant>>),
     SpawnJob(Pin<Box<dyn Future<Output = Option<M>> + Send + Sync + 'static>>),
+    OnTerminate(TerminateHook),
 }
 
 impl<M> fmt::Debug for CallMsg<M> {
@@ -19,8 +32,16 @@ impl<M> fmt::Debug for CallMsg<M> {
             Self::Exit(reason) => f.debug_tuple("Exit").field(reason).finish(),
             Self::Link(actor_id) => f.debug_tuple("Link").field(actor_id).finish(),
             Self::Unlink(actor_id) => f.debug_tuple("Unlink").field(actor_id).finish(),
+            Self::Monitor(monitor_ref, actor_id) =>
+                f.debug_tuple("Monitor").field(monitor_ref).field(actor_id).finish(),
+            Self::Demonitor(monitor_ref) => f.debug_tuple("Demonitor").field(monitor_ref).finish(),
             Self::TrapExit(trap_exit) => f.debug_tuple("TrapExit").field(trap_exit).finish(),
+            Self::SetTimer(timer_ref, deadline, _) =>
+                f.debug_tuple("SetTimer").field(timer_ref).field(deadline).finish(),
+            Self::CancelTimer(timer_ref, _) =>
+                f.debug_tuple("CancelTimer").field(timer_ref).finish(),
             Self::SpawnJob { .. } => f.debug_tuple("SpawnJob").finish(),
+            Self::OnTerminate { .. } => f.debug_tuple("OnTerminate").finish(),
         }
     }
 }
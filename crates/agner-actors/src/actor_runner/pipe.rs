@@ -1,7 +1,12 @@
+use std::sync::Arc;
+
 use agner_utils::spsc;
+use tokio::time::Instant;
+
+use crate::mailbox::InboxRoom;
 
 #[derive(Debug)]
-pub struct PipeRx<T>(spsc::Receiver<T>);
+pub struct PipeRx<T>(spsc::Receiver<T>, Option<Arc<InboxRoom>>);
 
 #[derive(Debug)]
 pub struct PipeTx<T>(spsc::Sender<T>, bool);
@@ -17,7 +22,7 @@ impl<T> PipeTx<T> {
 
 pub fn new<M>(max_len: usize) -> (PipeTx<M>, PipeRx<M>) {
     let (tx, rx) = spsc::channel(max_len);
-    (PipeTx(tx, false), PipeRx(rx))
+    (PipeTx(tx, false), PipeRx(rx, None))
 }
 
 impl<T> PipeTx<T>
@@ -28,6 +33,18 @@ where
         self.0.send(message, self.1).await
     }
 
+    pub async fn send_evicting(&mut self, message: T) -> Option<T> {
+        self.0.send_evicting(message).await
+    }
+
+    pub async fn ready(&mut self) {
+        self.0.ready().await
+    }
+
+    pub async fn received_count(&self) -> usize {
+        self.0.received_count().await
+    }
+
     pub async fn len(&self) -> (usize, usize)
     where
         T: Unpin,
@@ -36,12 +53,50 @@ where
     }
 }
 
+impl<T> PipeRx<T> {
+    /// Give back the room in the msg-inbox for each received item.
+    pub fn giving_back(self, room: Option<Arc<InboxRoom>>) -> Self {
+        Self(self.0, room)
+    }
+
+    fn received(&self, item: T) -> T {
+        if let Some(room) = &self.1 {
+            room.give_back();
+        }
+        item
+    }
+}
+
 impl<T> PipeRx<T>
 where
     T: Unpin,
 {
     pub async fn recv(&mut self) -> T {
-        self.0.recv(true).await.unwrap()
+        let item = self.0.recv(true).await.unwrap();
+        self.received(item)
+    }
+
+    /// Receive an item, unless the `deadline` is reached first.
+    ///
+    /// An item is only taken from the queue when it is returned, so that nothing is lost if the
+    /// deadline is reached or the returned future is dropped. An available item is returned even
+    /// if the deadline has already passed.
+    pub async fn recv_until(&mut self, deadline: Instant) -> Option<T> {
+        let item = tokio::select! {
+            biased;
+
+            item = self.0.recv(true) => item,
+            () = tokio::time::sleep_until(deadline) => None,
+        };
+        item.map(|item| self.received(item))
+    }
+
+    pub async fn recv_matching<F>(&mut self, predicate: F) -> T
+    where
+        F: FnMut(&T) -> bool + Unpin,
+    {
+        let item = self.0.recv_matching(predicate, true).await.unwrap();
+        self.received(item)
     }
 
     pub async fn len(&self) -> (usize, usize)
@@ -0,0 +1,181 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tokio::sync::Notify;
+
+use crate::actor_id::ActorID;
+use crate::exit::{BackendFailure, Exit};
+use crate::system_config::SlowPollWatchdog;
+
+use super::sys_msg::ActorStats;
+
+/// The poll-durations of a future, recorded by [`PollTimed`].
+#[derive(Debug, Default)]
+pub(crate) struct PollStats {
+    count: AtomicU64,
+    total_nanos: AtomicU64,
+    max_nanos: AtomicU64,
+}
+
+/// Reports the slow polls of an actor's futures, and trips once there have been too many of them.
+#[derive(Debug)]
+pub(crate) struct Watchdog {
+    actor_id: ActorID,
+    behaviour: &'static str,
+    config: SlowPollWatchdog,
+    violations: AtomicUsize,
+    tripped: Notify,
+}
+
+/// A wrapper measuring the duration of each poll of the inner future.
+#[pin_project::pin_project]
+pub(crate) struct PollTimed<F> {
+    #[pin]
+    inner: F,
+    stats: Option<Arc<PollStats>>,
+    watchdog: Option<(Arc<Watchdog>, &'static str)>,
+}
+
+/// The counters maintained by the actor's backend.
+#[derive(Debug)]
+pub(crate) struct BackendStats {
+    pub spawned_at: Instant,
+    pub messages_received: u64,
+    pub urgent_received: u64,
+    pub signals_received: u64,
+    pub jobs_spawned: u64,
+    pub jobs_completed: u64,
+    pub last_message_at: Option<Instant>,
+}
+
+impl PollStats {
+    pub fn record(&self, elapsed: Duration) {
+        let nanos = elapsed.as_nanos().try_into().unwrap_or(u64::MAX);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.total_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_nanos.fetch_max(nanos, Ordering::Relaxed);
+    }
+}
+
+impl Watchdog {
+    pub fn new(actor_id: ActorID, behaviour: &'static str, config: SlowPollWatchdog) -> Self {
+        Self { actor_id, behaviour, config, violations: Default::default(), tripped: Notify::new() }
+    }
+
+    pub fn violations(&self) -> usize {
+        self.violations.load(Ordering::Relaxed)
+    }
+
+    /// Resolves once the number of slow polls reaches `kill_after`.
+    pub async fn tripped(&self) -> Exit {
+        self.tripped.notified().await;
+        BackendFailure::SlowPoll { violations: self.violations(), threshold: self.config.threshold }
+            .into()
+    }
+
+    fn check(&self, polled: &'static str, elapsed: Duration) {
+        if elapsed <= self.config.threshold {
+            return
+        }
+        let violations = self.violations.fetch_add(1, Ordering::Relaxed) + 1;
+
+        tracing::warn!(
+            actor_id = display(self.actor_id),
+            behaviour = self.behaviour,
+            polled,
+            duration = ?elapsed,
+            threshold = ?self.config.threshold,
+            violations,
+            "slow poll"
+        );
+
+        if self.config.kill_after == Some(violations) {
+            self.tripped.notify_one();
+        }
+    }
+}
+
+impl<F> PollTimed<F> {
+    pub fn new(inner: F) -> Self {
+        Self { inner, stats: None, watchdog: None }
+    }
+
+    pub fn with_stats(self, stats: Arc<PollStats>) -> Self {
+        Self { stats: Some(stats), ..self }
+    }
+
+    /// Report the slow polls of this future to the `watchdog`, naming the future as `polled`.
+    pub fn with_watchdog(self, watchdog: Option<Arc<Watchdog>>, polled: &'static str) -> Self {
+        Self { watchdog: watchdog.map(|w| (w, polled)), ..self }
+    }
+}
+
+impl<F> Future for PollTimed<F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if this.stats.is_none() && this.watchdog.is_none() {
+            return this.inner.poll(cx)
+        }
+
+        let started_at = Instant::now();
+        let poll = this.inner.poll(cx);
+        let elapsed = started_at.elapsed();
+
+        if let Some(stats) = this.stats {
+            stats.record(elapsed);
+        }
+        if let Some((watchdog, polled)) = this.watchdog {
+            watchdog.check(polled, elapsed);
+        }
+        poll
+    }
+}
+
+impl Default for BackendStats {
+    fn default() -> Self {
+        Self {
+            spawned_at: Instant::now(),
+            messages_received: 0,
+            urgent_received: 0,
+            signals_received: 0,
+            jobs_spawned: 0,
+            jobs_completed: 0,
+            last_message_at: None,
+        }
+    }
+}
+
+impl BackendStats {
+    pub fn report(
+        &self,
+        poll_stats: &PollStats,
+        watchdog: Option<&Watchdog>,
+        messages_processed: u64,
+        urgent_processed: u64,
+    ) -> ActorStats {
+        ActorStats {
+            uptime: self.spawned_at.elapsed(),
+            messages_received: self.messages_received,
+            messages_processed,
+            urgent_received: self.urgent_received,
+            urgent_processed,
+            signals_received: self.signals_received,
+            since_last_message: self.last_message_at.map(|at| at.elapsed()),
+            jobs_spawned: self.jobs_spawned,
+            jobs_completed: self.jobs_completed,
+            polls_count: poll_stats.count.load(Ordering::Relaxed),
+            poll_time_total: Duration::from_nanos(poll_stats.total_nanos.load(Ordering::Relaxed)),
+            poll_time_max: Duration::from_nanos(poll_stats.max_nanos.load(Ordering::Relaxed)),
+            slow_polls: watchdog.map(Watchdog::violations).unwrap_or_default(),
+        }
+    }
+}
@@ -1,7 +1,11 @@
+use std::time::Duration;
+
 use tokio::sync::oneshot;
 
 use crate::actor_id::ActorID;
 use crate::exit::Exit;
+use crate::monitor::MonitorRef;
+use crate::system::SystemEvent;
 
 use super::Backend;
 
@@ -10,6 +14,9 @@ pub enum SysMsg {
     Link(ActorID),
     Unlink(ActorID),
     SigExit(ActorID, Exit),
+    Monitor(MonitorRef, ActorID),
+    Demonitor(MonitorRef),
+    Down(MonitorRef, ActorID, Exit),
     GetInfo(oneshot::Sender<ActorInfo>),
 }
 
@@ -25,19 +32,74 @@ pub struct ActorInfo {
     pub args_type: &'static str,
     pub message_type: &'static str,
     pub m_queue_len: (usize, usize),
+    /// the length of the urgent lane of the msg-inbox (unbounded)
+    pub u_queue_len: (usize, usize),
     pub s_queue_len: (usize, usize),
+    /// the number of signals waiting in the overflow queue for room in the signal-inbox
+    pub s_spilled_len: usize,
+    /// the number of signals that have been put into the overflow queue so far
+    pub s_spilled_total: usize,
     pub c_queue_len: (usize, usize),
     pub tasks_count: usize,
+    pub timers_count: usize,
     pub trap_exit: bool,
     pub links: Box<[ActorID]>,
+    pub monitors: Box<[(MonitorRef, ActorID)]>,
+    pub monitored_by: Box<[(MonitorRef, ActorID)]>,
+    pub stats: ActorStats,
+}
+
+/// Statistics collected by the actor's backend since the actor has been spawned.
+///
+/// Returned as a part of [`ActorInfo`](crate::actor_runner::ActorInfo).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActorStats {
+    /// time since the actor has been spawned
+    pub uptime: Duration,
+    /// the number of messages put into the msg-inbox
+    pub messages_received: u64,
+    /// the number of messages taken from the msg-inbox by the behaviour
+    pub messages_processed: u64,
+    /// the number of messages received via the urgent lane
+    pub urgent_received: u64,
+    /// the number of messages taken from the urgent lane by the behaviour
+    pub urgent_processed: u64,
+    /// the number of signals put into the signal-inbox (or its overflow queue)
+    pub signals_received: u64,
+    /// time since the last message has been put into the msg-inbox
+    pub since_last_message: Option<Duration>,
+    /// the number of jobs spawned via [`Context::spawn_job`](crate::context::Context::spawn_job)
+    /// or [`Context::future_to_inbox`](crate::context::Context::future_to_inbox)
+    pub jobs_spawned: u64,
+    /// the number of the spawned jobs that have completed
+    pub jobs_completed: u64,
+    /// the number of times the behaviour future has been polled
+    pub polls_count: u64,
+    /// the total time spent polling the behaviour future
+    pub poll_time_total: Duration,
+    /// the duration of the longest poll of the behaviour future
+    pub poll_time_max: Duration,
+    /// the number of polls of the behaviour and its jobs reported by the
+    /// [slow-poll watchdog](crate::SlowPollWatchdog)
+    pub slow_polls: usize,
 }
 
-impl<M> Backend<M> {
-    pub(super) async fn send_sys_msg(&self, to: ActorID, sys_msg: SysMsg) -> bool {
+impl<M> Backend<M>
+where
+    M: Unpin + Send + 'static,
+{
+    pub(super) fn send_sys_msg(&self, to: ActorID, sys_msg: SysMsg) -> bool {
         if let Some(system) = self.system_opt.rc_upgrade() {
-            system.send_sys_msg(to, sys_msg).await
+            system.send_sys_msg(to, sys_msg)
         } else {
             false
         }
     }
+
+    pub(super) fn publish_event(&self, event: SystemEvent) {
+        if let Some(system) = self.system_opt.rc_upgrade() {
+            system.publish_event(event);
+        }
+    }
 }
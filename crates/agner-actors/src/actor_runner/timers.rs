@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use tokio::time::Instant;
+
+use crate::timer::TimerRef;
+
+use super::*;
+
+/// The timers started by an actor.
+///
+/// The timers with the same deadline fire in the order they were started.
+#[derive(Debug)]
+pub(crate) struct Timers<M> {
+    queue: BTreeMap<(Instant, TimerRef), M>,
+    deadlines: BTreeMap<TimerRef, Instant>,
+}
+
+impl<M> Default for Timers<M> {
+    fn default() -> Self {
+        Self { queue: Default::default(), deadlines: Default::default() }
+    }
+}
+
+impl<M> Timers<M> {
+    pub fn len(&self) -> usize {
+        self.deadlines.len()
+    }
+
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.queue.keys().next().map(|(deadline, _)| *deadline)
+    }
+
+    pub fn start(&mut self, timer_ref: TimerRef, deadline: Instant, message: M) {
+        self.queue.insert((deadline, timer_ref), message);
+        self.deadlines.insert(timer_ref, deadline);
+    }
+
+    pub fn cancel(&mut self, timer_ref: TimerRef) -> Option<Instant> {
+        let deadline = self.deadlines.remove(&timer_ref)?;
+        self.queue.remove(&(deadline, timer_ref));
+        Some(deadline)
+    }
+
+    pub fn pop_expired(&mut self, now: Instant) -> Option<M> {
+        let entry = self.queue.first_entry().filter(|e| e.key().0 <= now)?;
+        let ((_, timer_ref), message) = entry.remove_entry();
+        self.deadlines.remove(&timer_ref);
+        Some(message)
+    }
+}
+
+impl<M> Backend<M>
+where
+    M: Unpin + Send + 'static,
+{
+    pub(super) fn handle_call_set_timer(
+        &mut self,
+        timer_ref: TimerRef,
+        deadline: Instant,
+        message: M,
+    ) -> Result<(), Exit> {
+        tracing::trace!("starting timer {}", timer_ref);
+        self.timers.start(timer_ref, deadline, message);
+        Ok(())
+    }
+
+    pub(super) fn handle_call_cancel_timer(
+        &mut self,
+        timer_ref: TimerRef,
+        report_to: oneshot::Sender<Option<Instant>>,
+    ) -> Result<(), Exit> {
+        tracing::trace!("cancelling timer {}", timer_ref);
+        let _ = report_to.send(self.timers.cancel(timer_ref));
+        Ok(())
+    }
+
+    pub(super) async fn handle_timer_expired(&mut self) -> Result<(), Exit> {
+        while let Some(message) = self.timers.pop_expired(Instant::now()) {
+            self.handle_message_recv(Some(message)).await?;
+        }
+        Ok(())
+    }
+}
@@ -1,32 +1,63 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::actor_id::ActorID;
+use crate::monitor::MonitorRef;
+use crate::spawn_opts::SignalOverflow;
+use crate::system::SystemEvent;
 
 use super::*;
 
+/// The ordered collections keep the order in which the linked and the monitoring actors are
+/// notified independent of the hasher's random state.
 #[derive(Debug, Default)]
 pub(crate) struct Watches {
     pub trap_exit: bool,
-    pub links: HashSet<ActorID>,
+    pub links: BTreeSet<ActorID>,
+    pub monitors: BTreeMap<MonitorRef, ActorID>,
+    pub monitored_by: BTreeMap<MonitorRef, ActorID>,
 }
 
-impl<M> Backend<M> {
+impl<M> Backend<M>
+where
+    M: Unpin + Send + 'static,
+{
     #[tracing::instrument(skip_all, fields(
         actor_id = display(self.actor_id),
         exit_reason = display(exit_reason.pp())
     ))]
-    pub(super) async fn notify_linked_actors(&mut self, exit_reason: Exit) {
-        for linked in std::mem::take(&mut self.watches.links).drain() {
+    pub(super) fn notify_linked_actors(&mut self, exit_reason: Exit) {
+        for linked in std::mem::take(&mut self.watches.links) {
             if exit_reason.is_normal() {
-                self.send_sys_msg(linked, SysMsg::Unlink(self.actor_id)).await;
+                self.send_sys_msg(linked, SysMsg::Unlink(self.actor_id));
             } else {
                 tracing::trace!("notifying linked actor: {}", linked);
-                self.send_sys_msg(linked, SysMsg::SigExit(self.actor_id, exit_reason.to_owned()))
-                    .await;
+                self.send_sys_msg(linked, SysMsg::SigExit(self.actor_id, exit_reason.to_owned()));
             }
         }
     }
 
+    #[tracing::instrument(skip_all, fields(
+        actor_id = display(self.actor_id),
+        exit_reason = display(exit_reason.pp())
+    ))]
+    pub(super) fn notify_monitoring_actors(&mut self, exit_reason: Exit) {
+        for (monitor_ref, watcher) in std::mem::take(&mut self.watches.monitored_by) {
+            tracing::trace!("notifying monitoring actor: {} ({})", watcher, monitor_ref);
+            self.send_sys_msg(
+                watcher,
+                SysMsg::Down(monitor_ref, self.actor_id, exit_reason.to_owned()),
+            );
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(actor_id = display(self.actor_id)))]
+    pub(super) fn demonitor_monitored_actors(&mut self) {
+        for (monitor_ref, monitored) in std::mem::take(&mut self.watches.monitors) {
+            tracing::trace!("demonitoring {} ({})", monitored, monitor_ref);
+            self.send_sys_msg(monitored, SysMsg::Demonitor(monitor_ref));
+        }
+    }
+
     #[tracing::instrument(skip_all, fields(
         actor_id = display(self.actor_id),
         link_to = display(link_to))
@@ -34,8 +65,9 @@ impl<M> Backend<M> {
     pub(super) async fn do_link(&mut self, link_to: ActorID) {
         if self.watches.links.insert(link_to) {
             tracing::trace!("linking to {}", link_to);
+            self.publish_event(SystemEvent::Linked { actor_id: self.actor_id, to: link_to });
 
-            if !self.send_sys_msg(link_to, SysMsg::Link(self.actor_id)).await {
+            if !self.send_sys_msg(link_to, SysMsg::Link(self.actor_id)) {
                 let _ = self.sys_msg_tx.send(SysMsg::SigExit(link_to, Exit::no_actor()));
             }
         }
@@ -48,8 +80,12 @@ impl<M> Backend<M> {
     pub(super) async fn do_unlink(&mut self, unlink_from: ActorID) {
         if self.watches.links.remove(&unlink_from) {
             tracing::trace!("[{}] unlinking from {}", self.actor_id, unlink_from);
+            self.publish_event(SystemEvent::Unlinked {
+                actor_id: self.actor_id,
+                from: unlink_from,
+            });
 
-            self.send_sys_msg(unlink_from, SysMsg::Unlink(self.actor_id)).await;
+            self.send_sys_msg(unlink_from, SysMsg::Unlink(self.actor_id));
         }
     }
 
@@ -60,6 +96,7 @@ impl<M> Backend<M> {
         if self.watches.trap_exit != trap_exit {
             tracing::trace!("trap_exit = {}", trap_exit);
             self.watches.trap_exit = trap_exit;
+            self.publish_event(SystemEvent::TrapExit { actor_id: self.actor_id, trap_exit });
         }
         Ok(())
     }
@@ -106,14 +143,7 @@ impl<M> Backend<M> {
                 (false, true, _) => Err(exit_reason),
                 (false, false, _) => Err(Exit::linked(receiver_id, exit_reason)),
 
-                (true, _, _) => {
-                    let signal = Signal::Exit(receiver_id, exit_reason);
-                    self.signals_w
-                        .send(signal)
-                        .await
-                        .map_err(|_| BackendFailure::InboxFull("signals"))?;
-                    Ok(())
-                },
+                (true, _, _) => self.deliver_signal(Signal::Exit(receiver_id, exit_reason)).await,
             }
         } else {
             Ok(())
@@ -125,7 +155,9 @@ impl<M> Backend<M> {
         link_to = display(link_to)
     ))]
     pub(super) async fn handle_sys_msg_link(&mut self, link_to: ActorID) -> Result<(), Exit> {
-        self.watches.links.insert(link_to);
+        if self.watches.links.insert(link_to) {
+            self.publish_event(SystemEvent::Linked { actor_id: self.actor_id, to: link_to });
+        }
         Ok(())
     }
 
@@ -134,7 +166,131 @@ impl<M> Backend<M> {
         unlink_from = display(unlink_from)
     ))]
     pub(super) async fn handle_sys_msg_unlink(&mut self, unlink_from: ActorID) -> Result<(), Exit> {
-        self.watches.links.remove(&unlink_from);
+        if self.watches.links.remove(&unlink_from) {
+            self.publish_event(SystemEvent::Unlinked {
+                actor_id: self.actor_id,
+                from: unlink_from,
+            });
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, fields(
+        actor_id = display(self.actor_id),
+        monitor_ref = display(monitor_ref),
+        monitored = display(monitored)
+    ))]
+    pub(super) async fn handle_call_monitor(
+        &mut self,
+        monitor_ref: MonitorRef,
+        monitored: ActorID,
+    ) -> Result<(), Exit> {
+        tracing::trace!("monitoring {}", monitored);
+        self.watches.monitors.insert(monitor_ref, monitored);
+
+        if !self.send_sys_msg(monitored, SysMsg::Monitor(monitor_ref, self.actor_id)) {
+            let _ = self.sys_msg_tx.send(SysMsg::Down(monitor_ref, monitored, Exit::no_actor()));
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, fields(
+        actor_id = display(self.actor_id),
+        monitor_ref = display(monitor_ref)
+    ))]
+    pub(super) async fn handle_call_demonitor(
+        &mut self,
+        monitor_ref: MonitorRef,
+    ) -> Result<(), Exit> {
+        if let Some(monitored) = self.watches.monitors.remove(&monitor_ref) {
+            tracing::trace!("demonitoring {}", monitored);
+            self.send_sys_msg(monitored, SysMsg::Demonitor(monitor_ref));
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, fields(
+        actor_id = display(self.actor_id),
+        monitor_ref = display(monitor_ref),
+        watcher = display(watcher)
+    ))]
+    pub(super) async fn handle_sys_msg_monitor(
+        &mut self,
+        monitor_ref: MonitorRef,
+        watcher: ActorID,
+    ) -> Result<(), Exit> {
+        self.watches.monitored_by.insert(monitor_ref, watcher);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, fields(
+        actor_id = display(self.actor_id),
+        monitor_ref = display(monitor_ref)
+    ))]
+    pub(super) async fn handle_sys_msg_demonitor(
+        &mut self,
+        monitor_ref: MonitorRef,
+    ) -> Result<(), Exit> {
+        self.watches.monitored_by.remove(&monitor_ref);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, fields(
+        actor_id = display(self.actor_id),
+        monitor_ref = display(monitor_ref),
+        terminated = display(terminated),
+        exit_reason = display(exit_reason.pp())
+    ))]
+    pub(super) async fn handle_sys_msg_down(
+        &mut self,
+        monitor_ref: MonitorRef,
+        terminated: ActorID,
+        exit_reason: Exit,
+    ) -> Result<(), Exit> {
+        if self.watches.monitors.remove(&monitor_ref).is_some() {
+            tracing::trace!(
+                "[{}] Received Down({}, {}, ..)",
+                self.actor_id,
+                monitor_ref,
+                terminated
+            );
+            self.deliver_signal(Signal::Down(monitor_ref, terminated, exit_reason)).await
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(super) async fn deliver_signal(&mut self, signal: Signal) -> Result<(), Exit> {
+        self.stats.signals_received += 1;
+
+        if !self.signals_spilled.is_empty() {
+            self.spill_signal(signal);
+            return Ok(())
+        }
+
+        if let Err(rejected) = self.signals_w.send(signal).await {
+            match self.signal_overflow {
+                SignalOverflow::Crash => Err(BackendFailure::InboxFull("signals"))?,
+                SignalOverflow::Spill => self.spill_signal(rejected),
+            }
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub(super) async fn handle_signals_ready(&mut self) -> Result<(), Exit> {
+        while let Some(signal) = self.signals_spilled.pop_front() {
+            if let Err(rejected) = self.signals_w.send(signal).await {
+                self.signals_spilled.push_front(rejected);
+                break
+            }
+        }
         Ok(())
     }
+
+    fn spill_signal(&mut self, signal: Signal) {
+        tracing::trace!("signal-inbox full, spilling [spilled: {}]", self.signals_spilled.len());
+        self.signals_spilled.push_back(signal);
+        self.signals_spilled_total += 1;
+    }
 }
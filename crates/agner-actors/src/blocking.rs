@@ -0,0 +1,206 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use tokio::runtime::Handle;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::actor::Actor;
+use crate::actor_id::ActorID;
+use crate::actor_ref::ActorRef;
+use crate::context::{Context, Event, Received};
+use crate::exit::{self, Exit};
+use crate::system::{System, SystemWeakRef};
+
+/// A synchronous behaviour, that is run on a thread dedicated to blocking operations.
+///
+/// `Blocking(behaviour)` is an [`Actor`]: thus it can be spawned in any way an async behaviour can
+/// (e.g. by a supervisor). The `behaviour` is a closure receiving a [`BlockingContext`] and the
+/// arguments:
+/// ```
+/// use agner_actors::{Blocking, BlockingContext, Exit, System};
+///
+/// fn echo(context: &mut BlockingContext<String>, _args: ()) -> Exit {
+///     while let Some(message) = context.recv_blocking() {
+///         eprintln!("received: {}", message);
+///     }
+///     Exit::normal()
+/// }
+/// let _ = async {
+///     let system = System::new(Default::default());
+///     let _ = system.spawn(Blocking(echo), (), Default::default()).await;
+/// };
+/// ```
+///
+/// Note: the behaviour cannot be interrupted while it blocks on anything other than the
+/// [`BlockingContext`]: should the actor be terminated (e.g. killed), the thread is released only
+/// when the behaviour returns.
+#[derive(Debug, Clone, Copy)]
+pub struct Blocking<F>(pub F);
+
+/// Blocking actor's API to itself (see [`Blocking`]).
+///
+/// The methods receiving from the actor's inbox return `None` once the actor has terminated: the
+/// behaviour should return then (the value it returns is ignored).
+#[derive(Debug)]
+pub struct BlockingContext<M> {
+    actor_id: ActorID,
+    myself: ActorRef<M>,
+    system: SystemWeakRef,
+    runtime: Handle,
+    calls: mpsc::Sender<BlockingCall<M>>,
+}
+
+#[derive(Debug)]
+enum BlockingCall<M> {
+    NextEvent(oneshot::Sender<Event<M>>),
+    NextMessage(Option<Duration>, oneshot::Sender<Received<M>>),
+    Link(ActorID, oneshot::Sender<()>),
+    TrapExit(bool, oneshot::Sender<()>),
+    Exit(Exit),
+}
+
+impl<'a, A, M, F, Out> Actor<'a, A, M> for Blocking<F>
+where
+    F: FnOnce(&mut BlockingContext<M>, A) -> Out + Send + 'static,
+    A: Send + 'static,
+    M: Unpin + Send + 'static,
+    Out: Into<Exit> + 'static,
+{
+    type Out = Exit;
+    type Fut = Pin<Box<dyn Future<Output = Exit> + Send + 'a>>;
+
+    fn run(self, context: &'a mut Context<M>, args: A) -> Self::Fut {
+        Box::pin(run_blocking(self.0, context, args))
+    }
+}
+
+/// Run the behaviour on a blocking thread, serving its calls to the context meanwhile.
+async fn run_blocking<F, A, M, Out>(behaviour: F, context: &mut Context<M>, args: A) -> Exit
+where
+    F: FnOnce(&mut BlockingContext<M>, A) -> Out + Send + 'static,
+    A: Send + 'static,
+    M: Unpin + Send + 'static,
+    Out: Into<Exit> + 'static,
+{
+    let (calls_tx, mut calls_rx) = mpsc::channel(1);
+    let mut blocking_context = BlockingContext {
+        actor_id: context.actor_id(),
+        myself: context.myself(),
+        system: context.system().rc_downgrade(),
+        runtime: Handle::current(),
+        calls: calls_tx,
+    };
+    // the panic is caught on the blocking thread, where its location has been recorded
+    let mut behaviour_running = tokio::task::spawn_blocking(move || {
+        exit::panic::catch_panic_sync(|| behaviour(&mut blocking_context, args).into())
+    });
+
+    loop {
+        tokio::select! {
+            biased;
+
+            Some(call) = calls_rx.recv() => serve_call(context, call).await,
+            joined = &mut behaviour_running => break match joined {
+                Ok(Ok(exit_reason) | Err(exit_reason)) => exit_reason,
+                Err(join_error) => Exit::from_message(join_error.to_string()),
+            },
+        }
+    }
+}
+
+async fn serve_call<M>(context: &mut Context<M>, call: BlockingCall<M>)
+where
+    M: Unpin,
+{
+    match call {
+        BlockingCall::NextEvent(reply_to) => {
+            let _ = reply_to.send(context.next_event().await);
+        },
+        BlockingCall::NextMessage(None, reply_to) => {
+            let _ = reply_to.send(Received::Event(context.next_message().await));
+        },
+        BlockingCall::NextMessage(Some(timeout), reply_to) => {
+            let _ = reply_to.send(context.next_message_timeout(timeout).await);
+        },
+        BlockingCall::Link(to, reply_to) => {
+            context.link(to).await;
+            let _ = reply_to.send(());
+        },
+        BlockingCall::TrapExit(trap_exit, reply_to) => {
+            context.trap_exit(trap_exit).await;
+            let _ = reply_to.send(());
+        },
+        BlockingCall::Exit(exit_reason) => match context.exit(exit_reason).await {},
+    }
+}
+
+impl<M> BlockingContext<M> {
+    /// Get current actor's [`ActorID`]
+    pub fn actor_id(&self) -> ActorID {
+        self.actor_id
+    }
+
+    /// Get a typed [`ActorRef`](crate::actor_ref::ActorRef) to this actor.
+    pub fn myself(&self) -> ActorRef<M> {
+        self.myself.to_owned()
+    }
+
+    /// Get the [`System`] this actor is running in.
+    pub fn system(&self) -> System {
+        self.system.rc_upgrade().expect("System gone")
+    }
+
+    /// Receive next event (message or signal).
+    pub fn next_event_blocking(&mut self) -> Option<Event<M>> {
+        self.call(BlockingCall::NextEvent)
+    }
+
+    /// Receive next message.
+    pub fn recv_blocking(&mut self) -> Option<M> {
+        self.call(|reply_to| BlockingCall::NextMessage(None, reply_to))?.event()
+    }
+
+    /// Receive next message, unless the `timeout` elapses first.
+    pub fn recv_timeout_blocking(&mut self, timeout: Duration) -> Option<Received<M>> {
+        self.call(|reply_to| BlockingCall::NextMessage(Some(timeout), reply_to))
+    }
+
+    /// Send a single message to the specified actor (see
+    /// [`System::send`](crate::system::System::send)).
+    pub fn send_blocking<T>(&self, to: ActorID, message: T)
+    where
+        T: Send + 'static,
+    {
+        if let Some(system) = self.system.rc_upgrade() {
+            self.runtime.block_on(system.send(to, message))
+        }
+    }
+
+    /// Link this actor to another actor.
+    pub fn link_blocking(&mut self, to: ActorID) {
+        self.call(|reply_to| BlockingCall::Link(to, reply_to));
+    }
+
+    /// Set whether this actor traps exits (see
+    /// [`Context::trap_exit`](crate::context::Context::trap_exit)).
+    pub fn trap_exit_blocking(&mut self, trap_exit: bool) {
+        self.call(|reply_to| BlockingCall::TrapExit(trap_exit, reply_to));
+    }
+
+    /// Exit with the provided reason.
+    ///
+    /// The behaviour should return right after this call.
+    pub fn exit(&mut self, exit_reason: Exit) {
+        let _ = self.calls.blocking_send(BlockingCall::Exit(exit_reason));
+    }
+
+    fn call<R>(
+        &mut self,
+        make_call: impl FnOnce(oneshot::Sender<R>) -> BlockingCall<M>,
+    ) -> Option<R> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.calls.blocking_send(make_call(reply_tx)).ok()?;
+        reply_rx.blocking_recv().ok()
+    }
+}
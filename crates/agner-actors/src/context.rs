@@ -1,21 +1,31 @@
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use futures::Future;
+use tokio::sync::oneshot;
+use tokio::time::Instant;
 
 use crate::actor_id::ActorID;
+use crate::actor_ref::ActorRef;
 use crate::actor_runner::call_msg::CallMsg;
 use crate::actor_runner::pipe::{PipeRx, PipeTx};
 use crate::exit::Exit;
 use crate::imports::Never;
-use crate::system::{System, SystemWeakRef};
+use crate::monitor::MonitorRef;
+use crate::system::{RefSeqs, System, SystemWeakRef};
+use crate::timer::TimerRef;
 
 /// Actor's API to itself
 #[derive(Debug)]
 pub struct Context<M> {
     actor_id: ActorID,
     system: SystemWeakRef,
+    ref_seqs: Arc<RefSeqs>,
+    myself: ActorRef<M>,
     messages: PipeRx<M>,
+    urgent: PipeRx<M>,
     signals: PipeRx<Signal>,
     calls: PipeTx<CallMsg<M>>,
     data: HashMap<TypeId, Box<dyn Any + Send + Sync + 'static>>,
@@ -30,12 +40,24 @@ pub enum Event<M> {
     Signal(Signal),
 }
 
+/// The outcome of receiving with a deadline (e.g.
+/// [`Context::next_event_until`](crate::context::Context::next_event_until)).
+#[derive(Debug)]
+pub enum Received<T> {
+    Event(T),
+    Timeout,
+}
+
 /// A signal received by an actor.
 ///
-/// Note: only actors that ["trap exits"](crate::context::Context::trap_exit) can handle signals.
+/// Note: only actors that ["trap exits"](crate::context::Context::trap_exit) can handle
+/// [`Signal::Exit`](crate::context::Signal::Exit). The
+/// [`Signal::Down`](crate::context::Signal::Down) is delivered to any actor that
+/// [monitors](crate::context::Context::monitor) another one.
 #[derive(Debug)]
 pub enum Signal {
     Exit(ActorID, Exit),
+    Down(MonitorRef, ActorID, Exit),
 }
 
 impl<M> Context<M> {
@@ -44,12 +66,20 @@ impl<M> Context<M> {
         self.actor_id
     }
 
+    /// Get a typed [`ActorRef`](crate::actor_ref::ActorRef) to this actor.
+    pub fn myself(&self) -> ActorRef<M> {
+        self.myself.to_owned()
+    }
+
     /// Get the [`System`] this actor is running in.
     pub fn system(&self) -> System {
         self.system.rc_upgrade().expect("System gone")
     }
 
     /// Receive next event (message or signal)
+    ///
+    /// The signals are received first, then the urgent messages (see
+    /// [`System::send_urgent`](crate::system::System::send_urgent)), then the regular ones.
     pub async fn next_event(&mut self) -> Event<M>
     where
         M: Unpin,
@@ -59,17 +89,80 @@ impl<M> Context<M> {
 
             signal = self.signals.recv() =>
                 Event::Signal(signal),
+            message = self.urgent.recv() =>
+                Event::Message(message),
             message = self.messages.recv() =>
                 Event::Message(message),
         }
     }
 
-    /// Receive next message.
+    /// Receive next event (message or signal), unless the `deadline` is reached first.
+    ///
+    /// An event that is already in the inbox is received even if the `deadline` has passed. No
+    /// event is lost if the returned future is dropped.
+    pub async fn next_event_until(&mut self, deadline: Instant) -> Received<Event<M>>
+    where
+        M: Unpin,
+    {
+        tokio::select! {
+            biased;
+
+            signal = self.signals.recv() =>
+                Received::Event(Event::Signal(signal)),
+            message = self.urgent.recv() =>
+                Received::Event(Event::Message(message)),
+            message = self.messages.recv_until(deadline) =>
+                message.map(Event::Message).into(),
+        }
+    }
+
+    /// Receive next event (message or signal), unless the `timeout` elapses first.
+    ///
+    /// See [`Context::next_event_until`](crate::context::Context::next_event_until).
+    pub async fn next_event_timeout(&mut self, timeout: Duration) -> Received<Event<M>>
+    where
+        M: Unpin,
+    {
+        self.next_event_until(Instant::now() + timeout).await
+    }
+
+    /// Receive next message (the urgent messages first).
     pub async fn next_message(&mut self) -> M
     where
         M: Unpin,
     {
-        self.messages.recv().await
+        tokio::select! {
+            biased;
+
+            message = self.urgent.recv() => message,
+            message = self.messages.recv() => message,
+        }
+    }
+
+    /// Receive next message, unless the `deadline` is reached first.
+    ///
+    /// A message that is already in the inbox is received even if the `deadline` has passed. No
+    /// message is lost if the returned future is dropped.
+    pub async fn next_message_until(&mut self, deadline: Instant) -> Received<M>
+    where
+        M: Unpin,
+    {
+        tokio::select! {
+            biased;
+
+            message = self.urgent.recv() => Received::Event(message),
+            message = self.messages.recv_until(deadline) => message.into(),
+        }
+    }
+
+    /// Receive next message, unless the `timeout` elapses first.
+    ///
+    /// See [`Context::next_message_until`](crate::context::Context::next_message_until).
+    pub async fn next_message_timeout(&mut self, timeout: Duration) -> Received<M>
+    where
+        M: Unpin,
+    {
+        self.next_message_until(Instant::now() + timeout).await
     }
 
     /// Receive next signal.
@@ -77,6 +170,40 @@ impl<M> Context<M> {
         self.signals.recv().await
     }
 
+    /// Receive the first message satisfying the `predicate`.
+    ///
+    /// The messages that do not match are kept in the message-inbox in their original order, and
+    /// will be received by the subsequent calls. Since they still occupy the message-inbox, they
+    /// count towards its [size limit](crate::spawn_opts::SpawnOpts::with_msg_inbox_size).
+    ///
+    /// The urgent messages are examined before the regular ones. The signals are not affected by
+    /// this method.
+    pub async fn receive_matching<F>(&mut self, predicate: F) -> M
+    where
+        M: Unpin,
+        F: FnMut(&M) -> bool + Unpin,
+    {
+        // both lanes are examined by the same predicate, though never at the same time.
+        let predicate = Mutex::new(predicate);
+        let matches = |m: &M| (predicate.lock().expect("predicate panicked"))(m);
+        tokio::select! {
+            biased;
+
+            message = self.urgent.recv_matching(matches) => message,
+            message = self.messages.recv_matching(matches) => message,
+        }
+    }
+
+    /// Same as [`Context::receive_matching`](crate::context::Context::receive_matching), but gives
+    /// up upon reaching the `deadline`, returning `None`.
+    pub async fn receive_matching_until<F>(&mut self, predicate: F, deadline: Instant) -> Option<M>
+    where
+        M: Unpin,
+        F: FnMut(&M) -> bool + Unpin,
+    {
+        tokio::time::timeout_at(deadline, self.receive_matching(predicate)).await.ok()
+    }
+
     /// Exit with the provided reason
     pub async fn exit(&mut self, exit_reason: Exit) -> Never {
         self.backend_call(CallMsg::Exit(exit_reason)).await;
@@ -93,6 +220,64 @@ impl<M> Context<M> {
         self.backend_call(CallMsg::Unlink(from)).await;
     }
 
+    /// Monitor another actor.
+    ///
+    /// When the monitored actor terminates (with any reason, including
+    /// [`Exit::normal()`](crate::exit::Exit::normal)), this actor receives a
+    /// [`Signal::Down`](crate::context::Signal::Down) carrying the returned [`MonitorRef`]. Unlike
+    /// links, monitors are unidirectional and the `Down`-signal does not require this actor to
+    /// ["trap exits"](crate::context::Context::trap_exit).
+    ///
+    /// If there is no actor with the specified id, the `Down`-signal is delivered right away with
+    /// the reason [`Exit::no_actor()`](crate::exit::Exit::no_actor).
+    pub async fn monitor(&mut self, actor_id: ActorID) -> MonitorRef {
+        let monitor_ref = self.ref_seqs.new_monitor_ref();
+        self.backend_call(CallMsg::Monitor(monitor_ref, actor_id)).await;
+        monitor_ref
+    }
+
+    /// Remove the monitor previously installed via
+    /// [`Context::monitor`](crate::context::Context::monitor).
+    ///
+    /// Note: a `Down`-signal that has already been put into the signal-inbox is not removed.
+    pub async fn demonitor(&mut self, monitor_ref: MonitorRef) {
+        self.backend_call(CallMsg::Demonitor(monitor_ref)).await;
+    }
+
+    /// Send the `message` to this actor after the `delay`.
+    ///
+    /// The timer can be cancelled via
+    /// [`Context::cancel_timer`](crate::context::Context::cancel_timer). All the timers of an actor
+    /// are cancelled when it exits.
+    pub async fn send_after(&mut self, delay: Duration, message: M) -> TimerRef {
+        self.start_timer(delay, |_| message).await
+    }
+
+    /// Same as [`Context::send_after`](crate::context::Context::send_after), but the message is
+    /// constructed from the [`TimerRef`] of the started timer, so that the actor could tell which
+    /// timer has fired.
+    pub async fn start_timer<F>(&mut self, delay: Duration, make_message: F) -> TimerRef
+    where
+        F: FnOnce(TimerRef) -> M,
+    {
+        let timer_ref = self.ref_seqs.new_timer_ref();
+        let message = Box::new(make_message(timer_ref));
+        self.backend_call(CallMsg::SetTimer(timer_ref, Instant::now() + delay, message))
+            .await;
+        timer_ref
+    }
+
+    /// Cancel the timer.
+    ///
+    /// Returns the time that remained until the timer would have fired, or `None` if the timer
+    /// has already fired or been cancelled.
+    pub async fn cancel_timer(&mut self, timer_ref: TimerRef) -> Option<Duration> {
+        let (tx, rx) = oneshot::channel();
+        self.backend_call(CallMsg::CancelTimer(timer_ref, tx)).await;
+        let deadline = rx.await.ok().flatten()?;
+        Some(deadline.saturating_duration_since(Instant::now()))
+    }
+
     /// Set whether this actor upon receiving a [`Signal`](crate::context::Signal) will be able to
     /// handle it (`trap_exit = true`) or crash (`trap_exit = false`).
     pub async fn trap_exit(&mut self, trap_exit: bool) {
@@ -110,6 +295,26 @@ impl<M> Context<M> {
         .await
     }
 
+    /// Set the callback to be run upon this actor's termination.
+    ///
+    /// The callback is invoked with the exit reason after the behaviour has stopped being
+    /// processed, but before the linked and monitoring actors are notified. It is given at most
+    /// [`SystemConfig::actor_termination_timeout`](crate::system_config::SystemConfig::actor_termination_timeout)
+    /// to complete. The callback is not run when the actor is
+    /// [killed](crate::exit::Exit::kill).
+    ///
+    /// Setting a callback replaces the previously set one.
+    pub async fn on_terminate<F, Fut>(&mut self, callback: F)
+    where
+        F: FnOnce(Exit) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.backend_call(CallMsg::OnTerminate(Box::new(move |exit_reason| {
+            Box::pin(callback(exit_reason))
+        })))
+        .await
+    }
+
     /// Process the provided future "in background" and upon its completion send the output to the
     /// message-inbox.
     pub async fn future_to_inbox<F>(&mut self, fut: F)
@@ -123,6 +328,21 @@ impl<M> Context<M> {
         })))
         .await;
     }
+
+    /// Accept the messages of type `T` as well, converting them into `M` via `From<T>`.
+    ///
+    /// The messages of type `T` sent before the adapter has been added are not delivered. To
+    /// accept them right from the start, use
+    /// [`SpawnOpts::with_adapter`](crate::spawn_opts::SpawnOpts::with_adapter).
+    pub async fn add_adapter<T>(&mut self)
+    where
+        T: Send + Sync + 'static,
+        M: From<T> + Send + 'static,
+    {
+        let Some(system) = self.system.rc_upgrade() else { return };
+        let adapted_tx = Arc::new(self.myself.messages_tx().adapt::<T>());
+        system.actor_route_add_adapter(self.actor_id, TypeId::of::<T>(), adapted_tx);
+    }
 }
 
 /// "data-bag" related methods
@@ -177,17 +397,54 @@ impl<M> Context<M> {
     }
 }
 
+impl<T> Received<T> {
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout)
+    }
+
+    pub fn event(self) -> Option<T> {
+        self.into()
+    }
+}
+
+impl<T> From<Option<T>> for Received<T> {
+    fn from(event_opt: Option<T>) -> Self {
+        event_opt.map(Self::Event).unwrap_or(Self::Timeout)
+    }
+}
+
+impl<T> From<Received<T>> for Option<T> {
+    fn from(received: Received<T>) -> Self {
+        match received {
+            Received::Event(event) => Some(event),
+            Received::Timeout => None,
+        }
+    }
+}
+
 impl<M> Context<M> {
     /// Create a new instance of [`Context`]
     pub(crate) fn new(
-        actor_id: ActorID,
+        myself: ActorRef<M>,
         system: SystemWeakRef,
+        ref_seqs: Arc<RefSeqs>,
         inbox: PipeRx<M>,
+        urgent: PipeRx<M>,
         signals: PipeRx<Signal>,
         calls: PipeTx<CallMsg<M>>,
     ) -> Self {
         let calls = calls.blocking();
-        Self { actor_id, system, messages: inbox, signals, calls, data: Default::default() }
+        Self {
+            actor_id: myself.actor_id(),
+            system,
+            ref_seqs,
+            myself,
+            messages: inbox,
+            urgent,
+            signals,
+            calls,
+            data: Default::default(),
+        }
     }
 }
 
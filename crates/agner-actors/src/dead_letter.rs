@@ -0,0 +1,156 @@
+use std::any::Any;
+use std::fmt;
+
+use tokio::sync::mpsc;
+
+use crate::actor_id::ActorID;
+use crate::system::{ActorChannel, SendError};
+
+/// A message that could not be delivered by [`System::send`](crate::system::System::send), or
+/// that has been dropped by the recipient's [`InboxOverflow`](crate::spawn_opts::InboxOverflow)
+/// policy.
+pub struct DeadLetter {
+    /// the intended recipient of the message
+    pub to: ActorID,
+    /// the type of the message
+    pub type_name: &'static str,
+    /// why the message has not been delivered
+    pub reason: DeadLetterReason,
+    /// the message itself
+    pub payload: Box<dyn Any + Send>,
+}
+
+/// The reason a message ended up as a [`DeadLetter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum DeadLetterReason {
+    #[error("No such actor")]
+    NoActor,
+
+    #[error("Invalid message-type")]
+    InvalidMessageType,
+
+    #[error("Inbox full")]
+    InboxFull,
+
+    /// Dropped from (or not let into) a full msg-inbox, as per the
+    /// [`InboxOverflow`](crate::spawn_opts::InboxOverflow) policy of the recipient.
+    #[error("Dropped on inbox overflow")]
+    InboxOverflow,
+}
+
+/// `DeadLetterHandler` is an entity that is notified when a message cannot be delivered.
+///
+/// The dead-letter handler is specified for the whole system via
+/// [`SystemConfig::dead_letter_handler`](crate::system_config::SystemConfig::dead_letter_handler).
+///
+/// To route the dead letters to an actor, use an
+/// [`ActorChannel<DeadLetter>`](crate::system::ActorChannel) as a handler.
+pub trait DeadLetterHandler: fmt::Debug + Send + Sync + 'static {
+    fn on_dead_letter(&self, dead_letter: DeadLetter);
+}
+
+/// A no-op [`DeadLetterHandler`](crate::dead_letter::DeadLetterHandler), i.e. it drops the
+/// dead letters.
+#[derive(Debug, Clone, Copy)]
+pub struct NoopDeadLetterHandler;
+
+/// A [`DeadLetterHandler`](crate::dead_letter::DeadLetterHandler) that will log the dead letters.
+#[derive(Debug, Clone, Copy)]
+pub struct LogDeadLetterHandler;
+
+/// A [`DeadLetterHandler`](crate::dead_letter::DeadLetterHandler) invoking the wrapped function.
+#[derive(Clone, Copy)]
+pub struct DeadLetterCallback<F>(pub F);
+
+impl DeadLetter {
+    pub(crate) fn new<M>(to: ActorID, rejected: SendError<M>) -> Self
+    where
+        M: Send + 'static,
+    {
+        let reason = match rejected {
+            SendError::NoActor(_) => DeadLetterReason::NoActor,
+            SendError::InvalidMessageType(_) => DeadLetterReason::InvalidMessageType,
+            SendError::Full(_) => DeadLetterReason::InboxFull,
+        };
+        Self {
+            to,
+            type_name: std::any::type_name::<M>(),
+            reason,
+            payload: Box::new(rejected.into_inner()),
+        }
+    }
+
+    pub(crate) fn inbox_overflow<M>(to: ActorID, dropped: M) -> Self
+    where
+        M: Send + 'static,
+    {
+        Self {
+            to,
+            type_name: std::any::type_name::<M>(),
+            reason: DeadLetterReason::InboxOverflow,
+            payload: Box::new(dropped),
+        }
+    }
+
+    /// Take the message back, provided it is of the type `M`.
+    pub fn downcast<M: 'static>(self) -> Result<M, Self> {
+        match self.payload.downcast() {
+            Ok(message) => Ok(*message),
+            Err(payload) => Err(Self { payload, ..self }),
+        }
+    }
+}
+
+impl fmt::Debug for DeadLetter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeadLetter")
+            .field("to", &self.to)
+            .field("type_name", &self.type_name)
+            .field("reason", &self.reason)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DeadLetterHandler for NoopDeadLetterHandler {
+    fn on_dead_letter(&self, _dead_letter: DeadLetter) {}
+}
+
+impl DeadLetterHandler for LogDeadLetterHandler {
+    fn on_dead_letter(&self, dead_letter: DeadLetter) {
+        tracing::warn!(
+            "[{}] dead letter: {} [msg-type: {}]",
+            dead_letter.to,
+            dead_letter.reason,
+            dead_letter.type_name
+        );
+    }
+}
+
+impl DeadLetterHandler for ActorChannel<DeadLetter> {
+    fn on_dead_letter(&self, dead_letter: DeadLetter) {
+        let _ = self.send(dead_letter);
+    }
+}
+
+impl<F> DeadLetterHandler for DeadLetterCallback<F>
+where
+    F: Fn(DeadLetter) + Send + Sync + 'static,
+{
+    fn on_dead_letter(&self, dead_letter: DeadLetter) {
+        (self.0)(dead_letter)
+    }
+}
+
+impl<F> fmt::Debug for DeadLetterCallback<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DeadLetterCallback<{}>", std::any::type_name::<F>())
+    }
+}
+
+impl DeadLetterHandler for mpsc::UnboundedSender<DeadLetter> {
+    fn on_dead_letter(&self, dead_letter: DeadLetter) {
+        if let Err(rejected) = self.send(dead_letter) {
+            tracing::trace!("dead letter dropped: {:?}", rejected.0);
+        }
+    }
+}
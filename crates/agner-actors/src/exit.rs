@@ -1,9 +1,11 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::actor_id::ActorID;
 use crate::imports::ArcError;
 
 mod into_exit;
+pub(crate) mod panic;
 
 /// An reason an actor exited.
 ///
@@ -19,6 +21,15 @@ pub enum Exit {
 
     #[error("Custom")]
     Custom(#[source] ArcError),
+
+    /// The behaviour (or one of its jobs, or its terminate-hook) has panicked.
+    ///
+    /// The `location` is only recorded by the systems created with
+    /// [`SystemConfig::panic_locations`](crate::system_config::SystemConfig::panic_locations)
+    /// enabled, which it is not by default. Otherwise the `location` is `None`, displayed as
+    /// "unknown location".
+    #[error("Panic: {} (at {})", message, location.as_deref().unwrap_or("unknown location"))]
+    Panic { message: Arc<str>, location: Option<Arc<str>> },
 }
 
 /// Standard exit reasons.
@@ -51,6 +62,12 @@ pub enum BackendFailure {
 
     #[error("Rx Closed: {}", _0)]
     RxClosed(&'static str),
+
+    #[error("Slow Poll: {} polls took longer than {:?}", violations, threshold)]
+    SlowPoll { violations: usize, threshold: Duration },
+
+    #[error("Dropped")]
+    Dropped,
 }
 
 impl Default for Exit {
@@ -93,6 +110,9 @@ impl Exit {
     pub fn is_custom(&self) -> bool {
         matches!(self, Self::Custom(_))
     }
+    pub fn is_panic(&self) -> bool {
+        matches!(self, Self::Panic { .. })
+    }
 
     pub fn normal() -> Self {
         WellKnown::Normal.into()
@@ -0,0 +1,80 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::{Arc, Once};
+use std::task::{Context, Poll};
+
+use crate::exit::Exit;
+
+thread_local! {
+    static LAST_PANIC_LOCATION: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
+}
+
+/// Install a panic-hook that remembers the location of the latest panic on the current thread.
+///
+/// The hook is process-wide and stays installed for the lifetime of the process. The previously
+/// installed hook is still invoked.
+pub(crate) fn install_hook() {
+    static INSTALLED: Once = Once::new();
+
+    INSTALLED.call_once(|| {
+        let prev_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let location = info.location().map(|l| Arc::from(l.to_string()));
+            LAST_PANIC_LOCATION.with(|last| *last.borrow_mut() = location);
+            prev_hook(info)
+        }))
+    })
+}
+
+/// A wrapper catching the panics of the inner future, and turning them into [`Exit::Panic`].
+///
+/// The remembered location is reset before each poll: a location is only attributed to the panic
+/// if it has been recorded during the very poll that panicked.
+#[pin_project::pin_project]
+pub(crate) struct CatchPanic<F>(#[pin] F);
+
+pub(crate) fn catch_panic<F: Future>(inner: F) -> CatchPanic<F> {
+    CatchPanic(inner)
+}
+
+/// Run the closure, turning its panic into an [`Exit::Panic`] (see [`CatchPanic`]).
+pub(crate) fn catch_panic_sync<R>(f: impl FnOnce() -> R) -> Result<R, Exit> {
+    clear_location();
+    std::panic::catch_unwind(AssertUnwindSafe(f)).map_err(exit_from_payload)
+}
+
+impl<F: Future> Future for CatchPanic<F> {
+    type Output = Result<F::Output, Exit>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.project().0;
+        match catch_panic_sync(|| inner.poll(cx)) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(out)) => Poll::Ready(Ok(out)),
+            Err(exit_reason) => Poll::Ready(Err(exit_reason)),
+        }
+    }
+}
+
+fn clear_location() {
+    LAST_PANIC_LOCATION.with(|last| *last.borrow_mut() = None);
+}
+
+/// Turn the payload of a caught panic into an [`Exit::Panic`].
+///
+/// Should be invoked on the same thread the panic has been caught on.
+fn exit_from_payload(payload: Box<dyn Any + Send + 'static>) -> Exit {
+    let message: Arc<str> = if let Some(s) = payload.downcast_ref::<&'static str>() {
+        Arc::from(*s)
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        Arc::from(s.as_str())
+    } else {
+        Arc::from("Box<dyn Any>")
+    };
+    let location = LAST_PANIC_LOCATION.with(|last| last.borrow_mut().take());
+
+    Exit::Panic { message, location }
+}
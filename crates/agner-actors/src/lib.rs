@@ -3,28 +3,46 @@
 
 mod actor;
 mod actor_id;
+mod actor_ref;
 mod actor_runner;
+mod blocking;
 mod context;
+mod dead_letter;
 mod exit;
 mod exit_handler;
+mod mailbox;
+mod monitor;
+mod parent_actor;
 mod spawn_opts;
+mod spawner;
 mod system;
 mod system_config;
+mod timer;
 
 mod exports {
-    pub use crate::actor::Actor;
+    pub use crate::actor::{Actor, LocalActor};
     pub use crate::actor_id::ActorID;
-    pub use crate::context::{Context, Event, Signal};
+    pub use crate::actor_ref::ActorRef;
+    pub use crate::blocking::{Blocking, BlockingContext};
+    pub use crate::context::{Context, Event, Received, Signal};
+    pub use crate::dead_letter::{DeadLetter, DeadLetterHandler, DeadLetterReason};
     pub use crate::exit::{Exit, Shutdown};
     pub use crate::exit_handler::ExitHandler;
-    pub use crate::spawn_opts::SpawnOpts;
-    pub use crate::system::{ActorChannel, System, SystemWeakRef};
-    pub use crate::system_config::SystemConfig;
+    pub use crate::monitor::MonitorRef;
+    pub use crate::parent_actor::ParentActor;
+    pub use crate::spawn_opts::{InboxOverflow, SignalOverflow, SpawnOpts};
+    pub use crate::spawner::Spawner;
+    pub use crate::system::{
+        ActorChannel, BoundedActorChannel, ReplyTo, ShutdownReport, System, SystemEvent,
+        SystemWeakRef,
+    };
+    pub use crate::system_config::{SlowPollWatchdog, SystemConfig};
+    pub use crate::timer::TimerRef;
 
-    pub use crate::actor_runner::ActorInfo;
+    pub use crate::actor_runner::{ActorInfo, ActorStats};
 
     pub mod system_error {
-        pub use crate::system::{SysChannelError, SysSpawnError};
+        pub use crate::system::{CallError, SendError, SysChannelError, SysSpawnError};
     }
 
     pub mod exit_reason {
@@ -35,6 +53,18 @@ mod exports {
     pub mod exit_handlers {
         pub use crate::exit_handler::{LogExitHandler, NoopExitHandler};
     }
+
+    /// Standard [spawners](crate::spawner::Spawner)
+    pub mod spawners {
+        pub use crate::spawner::AmbientSpawner;
+    }
+
+    /// Standard [dead-letter handlers](crate::dead_letter::DeadLetterHandler)
+    pub mod dead_letter_handlers {
+        pub use crate::dead_letter::{
+            DeadLetterCallback, LogDeadLetterHandler, NoopDeadLetterHandler,
+        };
+    }
 }
 mod imports {
     use std::sync::Arc;
@@ -0,0 +1,339 @@
+use std::any::{Any, TypeId};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+
+use tokio::sync::{mpsc, Semaphore};
+
+use crate::spawn_opts::InboxOverflow;
+use crate::system::SendError;
+
+/// The sending side of the channel through which the messages get to the actor's backend.
+///
+/// The channel itself is unbounded. Unless the [`InboxOverflow`] policy
+/// [is bounded](InboxOverflow::is_bounded), the policy is applied by the backend; otherwise the
+/// senders take the room for each message from the [`InboxRoom`] shared with the actor.
+///
+/// Besides, there is an unbounded channel for the urgent messages, that the backend delivers
+/// before the regular ones (an adapted mailbox uses the urgent channel of the original one).
+#[derive(Debug)]
+pub(crate) struct MailboxTx<M> {
+    inbox_overflow: InboxOverflow,
+    chan: TxChan<M>,
+    urgent: Option<mpsc::UnboundedSender<M>>,
+    closing: Arc<RwLock<()>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct MailboxRx<M> {
+    rx: mpsc::UnboundedReceiver<M>,
+    room: Option<Arc<InboxRoom>>,
+    /// Held for reading by an adapter while it converts and sends a message, and for writing
+    /// while the mailbox is being closed: an adapter that has found the mailbox open is certain
+    /// to get the converted message through, and gives back the original one otherwise.
+    closing: Arc<RwLock<()>>,
+}
+
+pub(crate) type UrgentRx<M> = mpsc::UnboundedReceiver<M>;
+
+/// The room in a bounded msg-inbox, shared by the senders and the actor.
+///
+/// A sender takes a permit for each message, and the permit is given back once the behaviour
+/// receives the message: at most `size` messages are on their way to the behaviour, whether they
+/// are still in the channel or already in the msg-inbox.
+#[derive(Debug)]
+pub(crate) struct InboxRoom {
+    permits: Semaphore,
+    /// the messages put into the msg-inbox by the actor itself (the outcomes of its jobs), taken
+    /// when there was no permit left for them
+    overdrawn: std::sync::atomic::AtomicUsize,
+}
+
+#[derive(Debug)]
+enum TxChan<M> {
+    Unbounded(mpsc::UnboundedSender<M>),
+    Bounded(mpsc::UnboundedSender<M>, Arc<InboxRoom>),
+    Adapted(Arc<dyn AdaptedTx<M>>),
+}
+
+/// A sender of the messages of type `T` into a mailbox of another type.
+trait AdaptedTx<T>: fmt::Debug + Send + Sync {
+    fn send(&self, message: T) -> AdaptedSend<'_, T>;
+    fn try_send(&self, message: T) -> Result<(), SendError<T>>;
+    fn send_urgent(&self, message: T) -> Result<(), SendError<T>>;
+}
+
+/// Converts the messages into `M` before putting them into the actor's mailbox.
+struct Adapter<M>(MailboxTx<M>);
+
+/// A registration of a `From<T> for M` conversion, made before the actor's mailbox exists (see
+/// [`SpawnOpts::with_adapter`](crate::spawn_opts::SpawnOpts::with_adapter)).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MessageAdapter {
+    pub type_id: TypeId,
+    pub type_name: &'static str,
+    adapt: fn(&dyn Any) -> Option<AdaptedMailboxTx>,
+}
+
+pub(crate) type AdaptedMailboxTx = Arc<dyn Any + Send + Sync + 'static>;
+
+type AdaptedSend<'a, T> =
+    Pin<Box<dyn Future<Output = Result<(), SendError<T>>> + Send + Sync + 'a>>;
+
+pub(crate) fn new<M>(
+    inbox_overflow: InboxOverflow,
+    size: usize,
+) -> (MailboxTx<M>, MailboxRx<M>, UrgentRx<M>) {
+    let (urgent_tx, urgent_rx) = mpsc::unbounded_channel();
+    let (tx, rx) = mpsc::unbounded_channel();
+    let closing = Arc::new(RwLock::new(()));
+    let (chan, room) = if inbox_overflow.is_bounded() {
+        let room = Arc::new(InboxRoom::new(size.max(1)));
+        (TxChan::Bounded(tx, room.to_owned()), Some(room))
+    } else {
+        (TxChan::Unbounded(tx), None)
+    };
+    let rx = MailboxRx { rx, room, closing: closing.to_owned() };
+    (MailboxTx { inbox_overflow, chan, urgent: Some(urgent_tx), closing }, rx, urgent_rx)
+}
+
+impl<M> MailboxTx<M> {
+    /// Whether the senders take the room in the actor's msg-inbox (see
+    /// [`InboxOverflow::is_bounded`]), directly or via an adapter.
+    pub fn is_bounded(&self) -> bool {
+        self.inbox_overflow.is_bounded()
+    }
+
+    /// A sender of the messages of type `T`, converting them into `M`.
+    pub fn adapt<T>(&self) -> MailboxTx<T>
+    where
+        T: Send + Sync + 'static,
+        M: From<T> + Send + 'static,
+    {
+        MailboxTx {
+            inbox_overflow: self.inbox_overflow,
+            chan: TxChan::Adapted(Arc::new(Adapter(self.to_owned()))),
+            urgent: None,
+            closing: self.closing.to_owned(),
+        }
+    }
+
+    /// Send the message, waiting for the room in the mailbox if the policy is
+    /// [`InboxOverflow::Block`].
+    pub async fn send(&self, message: M) -> Result<(), SendError<M>> {
+        match (&self.chan, self.inbox_overflow) {
+            (TxChan::Bounded(tx, room), InboxOverflow::Block) =>
+                if room.take().await {
+                    tx.send(message).map_err(|rejected| SendError::NoActor(rejected.0))
+                } else {
+                    Err(SendError::NoActor(message))
+                },
+            (TxChan::Adapted(tx), _) => tx.send(message).await,
+            (_, _) => self.try_send(message),
+        }
+    }
+
+    /// Send the message without waiting.
+    pub fn try_send(&self, message: M) -> Result<(), SendError<M>> {
+        use tokio::sync::TryAcquireError;
+
+        match &self.chan {
+            TxChan::Unbounded(tx) =>
+                tx.send(message).map_err(|rejected| SendError::NoActor(rejected.0)),
+            TxChan::Bounded(tx, room) => match room.try_take() {
+                Ok(()) => tx.send(message).map_err(|rejected| SendError::NoActor(rejected.0)),
+                Err(TryAcquireError::NoPermits) => Err(SendError::Full(message)),
+                Err(TryAcquireError::Closed) => Err(SendError::NoActor(message)),
+            },
+            TxChan::Adapted(tx) => tx.try_send(message),
+        }
+    }
+
+    /// Send the message via the urgent channel, regardless of the [`InboxOverflow`] policy.
+    pub fn send_urgent(&self, message: M) -> Result<(), SendError<M>> {
+        match (&self.chan, &self.urgent) {
+            (TxChan::Adapted(tx), _) => tx.send_urgent(message),
+            (_, Some(urgent)) =>
+                urgent.send(message).map_err(|rejected| SendError::NoActor(rejected.0)),
+            (_, None) => unreachable!("only an adapted mailbox has no urgent channel"),
+        }
+    }
+}
+
+impl<T, M> AdaptedTx<T> for Adapter<M>
+where
+    T: Send + Sync + 'static,
+    M: From<T> + Send + 'static,
+{
+    fn send(&self, message: T) -> AdaptedSend<'_, T> {
+        Box::pin(async move {
+            match (&self.0.chan, self.0.inbox_overflow) {
+                (TxChan::Bounded(tx, room), InboxOverflow::Block) =>
+                    if room.take().await {
+                        self.send_converted(tx, message)
+                    } else {
+                        Err(SendError::NoActor(message))
+                    },
+                (_, _) => self.try_send(message),
+            }
+        })
+    }
+
+    fn try_send(&self, message: T) -> Result<(), SendError<T>> {
+        use tokio::sync::TryAcquireError;
+
+        match &self.0.chan {
+            TxChan::Unbounded(tx) => self.send_converted(tx, message),
+            TxChan::Bounded(tx, room) => match room.try_take() {
+                Ok(()) => self.send_converted(tx, message),
+                Err(TryAcquireError::NoPermits) => Err(SendError::Full(message)),
+                Err(TryAcquireError::Closed) => Err(SendError::NoActor(message)),
+            },
+            TxChan::Adapted(_) =>
+                unreachable!("adapters are only created for the actor's own mailbox"),
+        }
+    }
+
+    fn send_urgent(&self, message: T) -> Result<(), SendError<T>> {
+        match &self.0.urgent {
+            Some(urgent) => self.send_converted(urgent, message),
+            None => unreachable!("adapters are only created for the actor's own mailbox"),
+        }
+    }
+}
+
+impl<M> Adapter<M> {
+    /// Convert the message and send it, unless the mailbox is closed.
+    ///
+    /// The message is converted only once it is certain to be accepted, so that a rejected
+    /// message could be given back: the mailbox cannot get closed while the `closing`-lock is
+    /// held for reading.
+    fn send_converted<T>(
+        &self,
+        tx: &mpsc::UnboundedSender<M>,
+        message: T,
+    ) -> Result<(), SendError<T>>
+    where
+        M: From<T>,
+    {
+        let _open = self.0.closing.read().expect("poisoned lock");
+        if tx.is_closed() {
+            return Err(SendError::NoActor(message))
+        }
+        let sent = tx.send(M::from(message));
+        debug_assert!(sent.is_ok(), "the mailbox got closed while the lock was held");
+        Ok(())
+    }
+}
+
+impl<M> fmt::Debug for Adapter<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Adapter<{}>", std::any::type_name::<M>())
+    }
+}
+
+impl MessageAdapter {
+    pub fn new<T, M>() -> Self
+    where
+        T: Send + Sync + 'static,
+        M: From<T> + Send + 'static,
+    {
+        fn adapt<T, M>(messages_tx: &dyn Any) -> Option<AdaptedMailboxTx>
+        where
+            T: Send + Sync + 'static,
+            M: From<T> + Send + 'static,
+        {
+            let messages_tx = messages_tx.downcast_ref::<MailboxTx<M>>()?;
+            Some(Arc::new(messages_tx.adapt::<T>()))
+        }
+
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            adapt: adapt::<T, M>,
+        }
+    }
+
+    /// Adapt the actor's mailbox, provided it is a `MailboxTx<M>`.
+    pub fn adapt(&self, messages_tx: &dyn Any) -> Option<AdaptedMailboxTx> {
+        (self.adapt)(messages_tx)
+    }
+}
+
+impl<M> Clone for MailboxTx<M> {
+    fn clone(&self) -> Self {
+        let chan = match &self.chan {
+            TxChan::Unbounded(tx) => TxChan::Unbounded(tx.to_owned()),
+            TxChan::Bounded(tx, room) => TxChan::Bounded(tx.to_owned(), room.to_owned()),
+            TxChan::Adapted(tx) => TxChan::Adapted(tx.to_owned()),
+        };
+        Self {
+            inbox_overflow: self.inbox_overflow,
+            chan,
+            urgent: self.urgent.to_owned(),
+            closing: self.closing.to_owned(),
+        }
+    }
+}
+
+impl<M> MailboxRx<M> {
+    pub async fn recv(&mut self) -> Option<M> {
+        self.rx.recv().await
+    }
+
+    /// Close the mailbox along with its urgent channel.
+    pub fn close(&mut self, urgent_rx: &mut UrgentRx<M>) {
+        let _closing = self.closing.write().expect("poisoned lock");
+        self.rx.close();
+        urgent_rx.close();
+        if let Some(room) = &self.room {
+            room.permits.close();
+        }
+    }
+
+    /// The room in the msg-inbox, if the policy [is bounded](InboxOverflow::is_bounded).
+    pub fn room(&self) -> Option<&Arc<InboxRoom>> {
+        self.room.as_ref()
+    }
+}
+
+impl InboxRoom {
+    fn new(size: usize) -> Self {
+        Self { permits: Semaphore::new(size), overdrawn: Default::default() }
+    }
+
+    /// Wait for the room for a message. Returns `false` if the actor has exited.
+    async fn take(&self) -> bool {
+        self.permits.acquire().await.map(|permit| permit.forget()).is_ok()
+    }
+
+    fn try_take(&self) -> Result<(), tokio::sync::TryAcquireError> {
+        self.permits.try_acquire().map(|permit| permit.forget())
+    }
+
+    /// Take the room for a message put into the msg-inbox by the actor itself, even if there is
+    /// none left.
+    pub fn take_by_actor(&self) {
+        use std::sync::atomic::Ordering;
+
+        if self.try_take().is_err() {
+            self.overdrawn.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Give back the room taken by a message that the behaviour has received.
+    pub fn give_back(&self) {
+        use std::sync::atomic::Ordering;
+
+        let repaid = self
+            .overdrawn
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |overdrawn| {
+                overdrawn.checked_sub(1)
+            })
+            .is_ok();
+        if !repaid {
+            self.permits.add_permits(1);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt;
+
+/// A reference to a monitor, installed via [`Context::monitor`](crate::context::Context::monitor).
+///
+/// The same reference is carried by the [`Signal::Down`](crate::context::Signal::Down) delivered
+/// upon the monitored actor's termination, and can be used to remove the monitor via
+/// [`Context::demonitor`](crate::context::Context::demonitor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonitorRef(usize);
+
+impl MonitorRef {
+    /// Create a new [`MonitorRef`], unique within the [`System`](crate::system::System) it is
+    /// created by (see `RefSeqs::new_monitor_ref`).
+    pub(crate) fn new(seq: usize) -> Self {
+        Self(seq)
+    }
+}
+
+impl fmt::Display for MonitorRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#Ref<{}>", self.0)
+    }
+}
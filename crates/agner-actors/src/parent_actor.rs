@@ -0,0 +1,9 @@
+use crate::actor_id::ActorID;
+
+/// The actor responsible for the lifecycle of another actor (e.g. its supervisor).
+///
+/// It is stored in the [data](crate::system::System::put_data) of the child actor. The actors
+/// without a parent are considered top-level (see
+/// [`System::shutdown`](crate::system::System::shutdown)).
+#[derive(Debug, Clone, Copy)]
+pub struct ParentActor(pub ActorID);
@@ -1,9 +1,10 @@
 use std::any::{Any, TypeId};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 
 use crate::actor_id::ActorID;
 use crate::exit_handler::ExitHandler;
+use crate::mailbox::MessageAdapter;
 
 const DEFAULT_MSG_INBOX_SIZE: usize = 1024;
 const DEFAULT_SIG_INBOX_SIZE: usize = 16;
@@ -14,24 +15,84 @@ const DEFAULT_SIG_INBOX_SIZE: usize = 16;
 /// - the set of [actor-ids](crate::actor_id::ActorID) the newly spawned actor will be immediately
 ///   linked to;
 /// - the sizes for msg-inbox and signal-inbox;
+/// - the policies to apply when the [msg-inbox](crate::spawn_opts::InboxOverflow) or the
+///   [signal-inbox](crate::spawn_opts::SignalOverflow) is full;
 /// - [exit-handler](crate::exit_handler::ExitHandler);
+/// - the [message adapters](crate::spawn_opts::SpawnOpts::with_adapter);
 /// - a "bag" of arbitrary properties (identified by their types).
 #[derive(Debug)]
 pub struct SpawnOpts {
-    links: HashSet<ActorID>,
+    links: BTreeSet<ActorID>,
     msg_inbox_size: usize,
+    inbox_overflow: InboxOverflow,
     sig_inbox_size: usize,
+    signal_overflow: SignalOverflow,
     exit_handler: Option<Arc<dyn ExitHandler>>,
+    adapters: Vec<MessageAdapter>,
     data: HashMap<TypeId, Box<dyn Any + Send + Sync + 'static>>,
 }
 
+/// What happens to a message sent to an actor whose msg-inbox is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InboxOverflow {
+    /// The receiving actor exits with
+    /// [`BackendFailure::InboxFull`](crate::exit::BackendFailure::InboxFull).
+    #[default]
+    Crash,
+
+    /// The message being sent is dropped, and passed to the
+    /// [dead-letter handler](crate::dead_letter::DeadLetterHandler).
+    DropNewest,
+
+    /// The oldest message in the msg-inbox is dropped to make room for the one being sent, and
+    /// passed to the [dead-letter handler](crate::dead_letter::DeadLetterHandler).
+    DropOldest,
+
+    /// The message is rejected with [`SendError::Full`](crate::system::SendError::Full).
+    ///
+    /// [`System::send`](crate::system::System::send) silently drops the rejected message, use
+    /// [`System::try_send`](crate::system::System::try_send) to find out whether it has been
+    /// accepted.
+    Reject,
+
+    /// The sender waits until there is room for the message.
+    Block,
+}
+
+impl InboxOverflow {
+    /// Whether the senders are aware of the capacity of the msg-inbox.
+    pub fn is_bounded(&self) -> bool {
+        matches!(self, Self::Reject | Self::Block)
+    }
+}
+
+/// What happens to a signal delivered to an actor whose signal-inbox is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SignalOverflow {
+    /// The signal is put into an unbounded overflow queue, and is moved into the signal-inbox as
+    /// soon as there is room for it. The order of the signals is preserved.
+    ///
+    /// The overflow queue is not bounded: the exit-signals sent via
+    /// [`System::exit`](crate::system::System::exit) are queued however many there are. The
+    /// number of spilled signals is reported in [`ActorInfo`](crate::actor_runner::ActorInfo).
+    #[default]
+    Spill,
+
+    /// The receiving actor exits with
+    /// [`BackendFailure::InboxFull`](crate::exit::BackendFailure::InboxFull).
+    Crash,
+}
+
 impl Default for SpawnOpts {
     fn default() -> Self {
         Self {
             links: Default::default(),
             msg_inbox_size: DEFAULT_MSG_INBOX_SIZE,
+            inbox_overflow: Default::default(),
             sig_inbox_size: DEFAULT_SIG_INBOX_SIZE,
+            signal_overflow: Default::default(),
             exit_handler: None,
+            adapters: Default::default(),
             data: Default::default(),
         }
     }
@@ -67,6 +128,17 @@ impl SpawnOpts {
     pub fn msg_inbox_size(&self) -> usize {
         self.msg_inbox_size
     }
+
+    /// specify what happens to a message sent when the msg-inbox is full
+    pub fn with_inbox_overflow(mut self, inbox_overflow: InboxOverflow) -> Self {
+        self.inbox_overflow = inbox_overflow;
+        self
+    }
+
+    /// what happens to a message sent when the msg-inbox is full
+    pub fn inbox_overflow(&self) -> InboxOverflow {
+        self.inbox_overflow
+    }
 }
 
 impl SpawnOpts {
@@ -80,6 +152,17 @@ impl SpawnOpts {
     pub fn sig_inbox_size(&self) -> usize {
         self.sig_inbox_size
     }
+
+    /// specify what happens to a signal delivered when the signal-inbox is full
+    pub fn with_signal_overflow(mut self, signal_overflow: SignalOverflow) -> Self {
+        self.signal_overflow = signal_overflow;
+        self
+    }
+
+    /// what happens to a signal delivered when the signal-inbox is full
+    pub fn signal_overflow(&self) -> SignalOverflow {
+        self.signal_overflow
+    }
 }
 
 impl SpawnOpts {
@@ -109,3 +192,25 @@ impl SpawnOpts {
         self.exit_handler.take()
     }
 }
+
+impl SpawnOpts {
+    /// Make the spawned actor, whose message type is `M`, accept the messages of type `T` as well.
+    ///
+    /// The messages of type `T` sent to the actor are converted into `M` via `From<T>`. Should
+    /// the actor's message type differ from `M`, the spawn fails with
+    /// [`SysSpawnError::InvalidAdapter`](crate::system::SysSpawnError::InvalidAdapter).
+    ///
+    /// An actor can also add adapters itself via
+    /// [`Context::add_adapter`](crate::context::Context::add_adapter).
+    pub fn with_adapter<T, M>(mut self) -> Self
+    where
+        T: Send + Sync + 'static,
+        M: From<T> + Send + 'static,
+    {
+        self.adapters.push(MessageAdapter::new::<T, M>());
+        self
+    }
+    pub(crate) fn take_adapters(&mut self) -> Vec<MessageAdapter> {
+        std::mem::take(&mut self.adapters)
+    }
+}
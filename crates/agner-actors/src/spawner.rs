@@ -0,0 +1,35 @@
+use std::fmt;
+
+use futures::future::BoxFuture;
+use tokio::runtime::Handle;
+
+/// `Spawner` is an entity that runs the tasks of a [`System`](crate::system::System): the actors
+/// and the auxiliary tasks (e.g. the delayed sends).
+///
+/// The spawner is specified for the whole system via
+/// [`SystemConfig::spawner`](crate::system_config::SystemConfig::spawner). A tokio
+/// [`Handle`](tokio::runtime::Handle) is a `Spawner` too: thus the systems of a single process may
+/// run on separate runtimes.
+///
+/// The actors rely on the tokio facilities (timers, blocking threads): the tasks should be run
+/// within a tokio runtime.
+pub trait Spawner: fmt::Debug + Send + Sync + 'static {
+    fn spawn(&self, task: BoxFuture<'static, ()>);
+}
+
+/// A [`Spawner`](crate::spawner::Spawner) running the tasks on the runtime the
+/// [`System`](crate::system::System) is used from (i.e. via [`tokio::spawn`]).
+#[derive(Debug, Clone, Copy)]
+pub struct AmbientSpawner;
+
+impl Spawner for AmbientSpawner {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        tokio::spawn(task);
+    }
+}
+
+impl Spawner for Handle {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        Handle::spawn(self, task);
+    }
+}
@@ -1,19 +1,27 @@
 use std::any::Any;
+use std::collections::HashMap;
 use std::future::Future;
-use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::{AtomicBool, AtomicUsize};
 use std::sync::{Arc, Weak};
+use std::time::Duration;
 
 use agner_utils::std_error_pp::StdErrorPP;
-use futures::{stream, Stream, StreamExt};
-use tokio::sync::{mpsc, oneshot, RwLock};
+use futures::{stream, Stream};
+use tokio::sync::{broadcast, mpsc, oneshot, watch, RwLock};
+use tokio::task::LocalSet;
+use tokio::time::Instant;
 use tracing::Instrument;
 
-use crate::actor::Actor;
+use crate::actor::{Actor, AsLocal, LocalActor};
 use crate::actor_id::ActorID;
+use crate::actor_ref::ActorRef;
 use crate::actor_runner::sys_msg::{ActorInfo, SysMsg};
 use crate::actor_runner::ActorRunner;
+use crate::blocking::{Blocking, BlockingContext};
+use crate::dead_letter::DeadLetter;
 use crate::exit::Exit;
 use crate::exit_handler::ExitHandler;
+use crate::mailbox::{self, AdaptedMailboxTx, MailboxTx};
 use crate::spawn_opts::SpawnOpts;
 use crate::system_config::SystemConfig;
 
@@ -24,10 +32,94 @@ use actor_entry::ActorEntry;
 mod actor_id_pool;
 use actor_id_pool::ActorIDPool;
 
+mod actor_route;
+use actor_route::ActorRoute;
+
+mod actor_table;
+use actor_table::{ActorTable, Slot};
+
+mod refs;
+pub(crate) use refs::RefSeqs;
+
 mod errors;
-pub use errors::{SysChannelError, SysSpawnError};
+pub use errors::{CallError, SendError, SysChannelError, SysSpawnError};
+
+mod call;
+pub use call::ReplyTo;
+
+mod shutdown;
+pub use shutdown::ShutdownReport;
+
+mod events;
+pub use events::SystemEvent;
+
+/// A channel to an actor with an unbounded msg-inbox (see
+/// [`System::channel`](crate::system::System::channel)).
+///
+/// # Migrating from 0.4
+///
+/// Up to 0.4 this was an alias for `tokio::sync::mpsc::UnboundedSender<M>`; it is a type of its
+/// own since 0.5, as the messages sent through it may have to be converted by an
+/// [adapter](crate::spawn_opts::SpawnOpts::with_adapter). [`ActorChannel::send`] keeps the
+/// signature of `UnboundedSender::send`, save for the error type: match on
+/// [`SendError::NoActor`] instead of `tokio::sync::mpsc::error::SendError`.
+///
+/// [`System::channel`](crate::system::System::channel) now fails with
+/// [`SysChannelError::BoundedInbox`] for an actor with a
+/// [bounded](crate::spawn_opts::InboxOverflow::is_bounded) msg-inbox (in 0.4 no msg-inbox was
+/// bounded on the sending side): use a [`BoundedActorChannel`] for such actors.
+#[derive(Debug)]
+pub struct ActorChannel<M>(MailboxTx<M>);
+
+/// A channel to an actor, respecting the actor's
+/// [`InboxOverflow`](crate::spawn_opts::InboxOverflow) policy (see
+/// [`System::bounded_channel`](crate::system::System::bounded_channel)).
+#[derive(Debug)]
+pub struct BoundedActorChannel<M>(MailboxTx<M>);
+
+impl<M> ActorChannel<M> {
+    /// Send the message to the actor.
+    pub fn send(&self, message: M) -> Result<(), SendError<M>> {
+        self.0.try_send(message)
+    }
+
+    /// Send the message to the actor via the urgent lane (see
+    /// [`System::send_urgent`](crate::system::System::send_urgent)).
+    pub fn send_urgent(&self, message: M) -> Result<(), SendError<M>> {
+        self.0.send_urgent(message)
+    }
+}
 
-pub type ActorChannel<M> = mpsc::UnboundedSender<M>;
+impl<M> BoundedActorChannel<M> {
+    /// Send the message to the actor, waiting for the room in the actor's msg-inbox if its policy
+    /// is [`InboxOverflow::Block`](crate::spawn_opts::InboxOverflow::Block).
+    pub async fn send(&self, message: M) -> Result<(), SendError<M>> {
+        self.0.send(message).await
+    }
+
+    /// Send the message to the actor without waiting.
+    pub fn try_send(&self, message: M) -> Result<(), SendError<M>> {
+        self.0.try_send(message)
+    }
+
+    /// Send the message to the actor via the urgent lane (see
+    /// [`System::send_urgent`](crate::system::System::send_urgent)).
+    pub fn send_urgent(&self, message: M) -> Result<(), SendError<M>> {
+        self.0.send_urgent(message)
+    }
+}
+
+impl<M> Clone for ActorChannel<M> {
+    fn clone(&self) -> Self {
+        Self(self.0.to_owned())
+    }
+}
+
+impl<M> Clone for BoundedActorChannel<M> {
+    fn clone(&self) -> Self {
+        Self(self.0.to_owned())
+    }
+}
 
 /// A [`System`](crate::system::System) is a scope within which the actors run.
 #[derive(Debug, Clone)]
@@ -54,13 +146,27 @@ impl System {
 
         let system_id = NEXT_SYSTEM_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
-        let actor_id_pool = ActorIDPool::new(system_id, config.max_actors);
-        let actor_entries =
-            (0..config.max_actors).map(|_| RwLock::new(Default::default())).collect();
+        if config.panic_locations {
+            crate::exit::panic::install_hook();
+        }
+
+        let actor_id_pool = ActorIDPool::new(system_id);
+        let actor_entries = ActorTable::new();
 
         let exit_handler = config.exit_handler.to_owned();
+        let (events_tx, _) = broadcast::channel(config.events_buffer_size.max(1));
 
-        let inner = Inner { config, system_id, actor_id_pool, actor_entries, exit_handler };
+        let inner = Inner {
+            config,
+            system_id,
+            actor_id_pool,
+            actor_entries,
+            exit_handler,
+            shutting_down: AtomicBool::new(false),
+            spawn_gate: RwLock::new(()),
+            events_tx,
+            ref_seqs: Arc::new(RefSeqs::new()),
+        };
         Self(Arc::new(inner))
     }
 
@@ -91,50 +197,187 @@ impl System {
     ///     let bob = system.spawn(actor_behaviour, "Bob", Default::default()).await.expect("Failed to spawn an actor");
     /// };
     /// ```
+    pub async fn spawn<Behaviour, Args, Message>(
+        &self,
+        behaviour: Behaviour,
+        args: Args,
+        spawn_opts: SpawnOpts,
+    ) -> Result<ActorID, SysSpawnError>
+    where
+        Args: Send + 'static,
+        Message: Unpin + Send + 'static,
+        for<'a> Behaviour: Actor<'a, Args, Message>,
+    {
+        self.spawn_typed(behaviour, args, spawn_opts).await.map(ActorID::from)
+    }
+
+    /// Spawn an actor, and return a typed [`ActorRef`](crate::actor_ref::ActorRef) to it.
     #[tracing::instrument(skip_all, fields(
         sys_id = self.0.system_id,
         behaviour = std::any::type_name::<Behaviour>(),
     ))]
-    pub async fn spawn<Behaviour, Args, Message>(
+    pub async fn spawn_typed<Behaviour, Args, Message>(
         &self,
         behaviour: Behaviour,
         args: Args,
-        mut spawn_opts: SpawnOpts,
-    ) -> Result<ActorID, SysSpawnError>
+        spawn_opts: SpawnOpts,
+    ) -> Result<ActorRef<Message>, SysSpawnError>
     where
         Args: Send + 'static,
         Message: Unpin + Send + 'static,
         for<'a> Behaviour: Actor<'a, Args, Message>,
     {
+        let (actor, actor_ref) =
+            self.prepare_spawn(spawn_opts, std::any::type_name::<Behaviour>()).await?;
+        self.0.config.spawner.spawn(Box::pin(actor.run(AsLocal(behaviour), args)));
+
+        Ok(actor_ref)
+    }
+
+    /// Spawn an actor with a synchronous behaviour, run on a thread dedicated to blocking
+    /// operations (see [`Blocking`](crate::blocking::Blocking)), and return a typed
+    /// [`ActorRef`](crate::actor_ref::ActorRef) to it.
+    pub async fn spawn_blocking<Behaviour, Args, Message, Out>(
+        &self,
+        behaviour: Behaviour,
+        args: Args,
+        spawn_opts: SpawnOpts,
+    ) -> Result<ActorRef<Message>, SysSpawnError>
+    where
+        Behaviour: FnOnce(&mut BlockingContext<Message>, Args) -> Out + Send + 'static,
+        Args: Send + 'static,
+        Message: Unpin + Send + 'static,
+        Out: Into<Exit> + 'static,
+    {
+        self.spawn_typed(Blocking(behaviour), args, spawn_opts).await
+    }
+
+    /// Spawn an actor on the provided [`LocalSet`], and return a typed
+    /// [`ActorRef`](crate::actor_ref::ActorRef) to it.
+    ///
+    /// Neither the behaviour, nor its arguments are required to be `Send`: thus the actor may hold
+    /// `Rc`-based or thread-bound values. Otherwise the actor is not different from the actors
+    /// spawned via [`System::spawn`](crate::system::System::spawn): it can be sent messages to,
+    /// linked to, monitored and supervised.
+    ///
+    /// The actor runs as long as the `local_set` is being driven (e.g. via
+    /// [`LocalSet::run_until`]). Should the `local_set` be dropped before the actor exits, the
+    /// actor exits with [`BackendFailure::Dropped`](crate::exit::BackendFailure::Dropped).
+    #[tracing::instrument(skip_all, fields(
+        sys_id = self.0.system_id,
+        behaviour = std::any::type_name::<Behaviour>(),
+    ))]
+    pub async fn spawn_local<Behaviour, Args, Message>(
+        &self,
+        local_set: &LocalSet,
+        behaviour: Behaviour,
+        args: Args,
+        spawn_opts: SpawnOpts,
+    ) -> Result<ActorRef<Message>, SysSpawnError>
+    where
+        Args: 'static,
+        Message: Unpin + Send + 'static,
+        for<'a> Behaviour: LocalActor<'a, Args, Message>,
+    {
+        let (actor, actor_ref) =
+            self.prepare_spawn(spawn_opts, std::any::type_name::<Behaviour>()).await?;
+        local_set.spawn_local(actor.run(behaviour, args));
+
+        Ok(actor_ref)
+    }
+
+    /// Register a new actor in the system: the returned runner is yet to be spawned.
+    async fn prepare_spawn<Message>(
+        &self,
+        mut spawn_opts: SpawnOpts,
+        behaviour: &'static str,
+    ) -> Result<(ActorRunner<Message>, ActorRef<Message>), SysSpawnError>
+    where
+        Message: Unpin + Send + 'static,
+    {
+        // the check and the registration below are not interleaved with the start of a shutdown:
+        // the actor is either refused, or seen by the shutdown.
+        let _spawn_gate = self.0.spawn_gate.read().await;
+        if self.is_shutting_down() {
+            return Err(SysSpawnError::ShuttingDown)
+        }
+
         let exit_handler =
             spawn_opts.take_exit_handler().unwrap_or_else(|| self.0.exit_handler.to_owned());
 
         let system = self.to_owned();
-        let actor_id_lease =
-            system.0.actor_id_pool.acquire_id().ok_or(SysSpawnError::MaxActorsLimit)?;
+        let (actor_id_lease, actors_count) = system.0.actor_id_pool.acquire_id();
         let actor_id = *actor_id_lease;
-
-        let (messages_tx, messages_rx) = mpsc::unbounded_channel::<Message>();
+        // reported once each time the number of actors goes beyond the limit
+        let exceeded_max_actors =
+            self.0.config.max_actors.filter(|max_actors| actors_count == max_actors + 1);
+
+        let (messages_tx, messages_rx, urgent_rx) =
+            mailbox::new::<Message>(spawn_opts.inbox_overflow(), spawn_opts.msg_inbox_size());
+        let adapters = spawn_opts
+            .take_adapters()
+            .into_iter()
+            .map(|adapter| {
+                adapter
+                    .adapt(&messages_tx)
+                    .map(|adapted_tx| (adapter.type_id, adapted_tx))
+                    .ok_or(SysSpawnError::InvalidAdapter(adapter.type_name))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
         let (sys_msg_tx, sys_msg_rx) = mpsc::unbounded_channel();
+        let (exited_tx, exited_rx) = watch::channel(None);
 
         let actor = ActorRunner {
             actor_id,
             system_opt: system.rc_downgrade(),
             messages_rx,
+            messages_tx: messages_tx.to_owned(),
+            urgent_rx,
             sys_msg_rx,
             sys_msg_tx: sys_msg_tx.to_owned(),
+            exited_tx,
             exit_handler,
             spawn_opts,
+            slow_poll_watchdog: system.config().slow_poll_watchdog,
+            ref_seqs: system.0.ref_seqs.to_owned(),
         };
-        tokio::spawn(actor.run(behaviour, args));
 
-        let entry = ActorEntry::new(actor_id_lease, messages_tx, sys_msg_tx);
-        // let entry = ActorEntryOld { actor_id_lease, messages_tx: Box::new(messages_tx),
-        // sys_msg_tx };
+        let actor_ref = ActorRef::new(actor_id, messages_tx.to_owned(), system.rc_downgrade());
+        let entry = ActorEntry::new(actor_id_lease);
+        let route = ActorRoute::new(actor_id, messages_tx, adapters, sys_msg_tx, exited_rx);
+
+        // The entry should be in place before the actor starts running: otherwise an actor that
+        // exits right away would not find its entry to terminate.
+        self.actor_entry_put(entry, route).await;
+        self.publish_event(SystemEvent::Spawned { actor_id, behaviour });
+        if let Some(max_actors) = exceeded_max_actors {
+            tracing::warn!(
+                "the number of actors exceeds the max_actors limit ({}) [actor: {}]",
+                max_actors,
+                actor_id
+            );
+            self.publish_event(SystemEvent::MaxActorsExceeded { actor_id, max_actors });
+        }
 
-        self.actor_entry_put(entry).await;
+        Ok((actor, actor_ref))
+    }
 
-        Ok(actor_id)
+    /// Get a typed [`ActorRef`](crate::actor_ref::ActorRef) to the specified actor, provided it
+    /// accepts the messages of type `M`.
+    #[tracing::instrument(skip_all, fields(
+        sys_id = self.0.system_id,
+        actor_id = display(actor_id),
+        msg_type = std::any::type_name::<M>()
+    ))]
+    pub async fn actor_ref<M>(&self, actor_id: ActorID) -> Result<ActorRef<M>, SysChannelError>
+    where
+        M: Send + 'static,
+    {
+        match self.messages_tx::<M>(actor_id).await {
+            Ok(messages_tx) => Ok(ActorRef::new(actor_id, messages_tx, self.rc_downgrade())),
+            Err(SendError::InvalidMessageType(())) => Err(SysChannelError::InvalidMessageType),
+            Err(_) => Err(SysChannelError::NoActor),
+        }
     }
 
     /// Send SigExit to the specified actor.
@@ -144,7 +387,7 @@ impl System {
         exit_reason = display(exit_reason.pp())
     ))]
     pub async fn exit(&self, actor_id: ActorID, exit_reason: Exit) {
-        self.send_sys_msg(actor_id, SysMsg::SigExit(actor_id, exit_reason)).await;
+        self.send_sys_msg(actor_id, SysMsg::SigExit(actor_id, exit_reason));
     }
 
     /// Wait for the specified actor to terminate, and return upon its termination the
@@ -180,7 +423,7 @@ impl System {
         sys_id = self.0.system_id,
         to = display(to)
     ))]
-    pub(crate) async fn send_sys_msg(&self, to: ActorID, sys_msg: SysMsg) -> bool {
+    pub(crate) fn send_sys_msg(&self, to: ActorID, sys_msg: SysMsg) -> bool {
         tracing::trace!(
             "[sys:{}] trying to send sys-msg [to: {}, sys-msg: {:?}]",
             self.0.system_id,
@@ -188,21 +431,23 @@ impl System {
             sys_msg
         );
 
-        if let Some(entry) = self.actor_entry_read(to).await {
-            if entry.running_actor_id() == Some(to) {
-                if let Some(tx) = entry.sys_msg_tx() {
-                    return tx.send(sys_msg).is_ok()
-                } else {
-                    tracing::warn!("actor_entry is not occupied")
-                }
-            } else {
-                tracing::warn!("actor_id mismatch")
-            }
+        if let Some(route) = self.actor_route(to) {
+            route.sys_msg_tx().send(sys_msg).is_ok()
+        } else {
+            tracing::trace!("no actor_route");
+            false
         }
-        false
     }
 
     /// Send a single message to the specified actor.
+    ///
+    /// Should the actor's msg-inbox be full, the actor's
+    /// [`InboxOverflow`](crate::spawn_opts::InboxOverflow) policy applies. In particular, with
+    /// [`InboxOverflow::Block`](crate::spawn_opts::InboxOverflow::Block) this method waits until
+    /// there is room for the message.
+    ///
+    /// The messages that could not be delivered are passed to the
+    /// [`SystemConfig::dead_letter_handler`](crate::system_config::SystemConfig::dead_letter_handler).
     #[tracing::instrument(skip_all, fields(
         sys_id = self.0.system_id,
         to = display(to),
@@ -213,19 +458,132 @@ impl System {
         M: Send + 'static,
     {
         tracing::trace!("trying to send message",);
-        if let Some(entry) = self.actor_entry_read(to).await {
-            if entry.running_actor_id() == Some(to) {
-                if let Some(tx) = entry.messages_tx::<M>() {
-                    let _ = tx.send(message);
-                } else {
-                    tracing::warn!("message-type mismatch or actor_entry is not occupied");
-                }
-            } else {
-                tracing::warn!("actor_id mismatch")
-            }
+        if let Err(rejected) = self.send_checked(to, message).await {
+            tracing::trace!("message not sent: {}", rejected);
+            self.0.config.dead_letter_handler.on_dead_letter(DeadLetter::new(to, rejected));
+        }
+    }
+
+    /// Send a single message to the specified actor (same as
+    /// [`System::send`](crate::system::System::send)), giving the message back to the caller
+    /// should it not be delivered.
+    #[tracing::instrument(skip_all, fields(
+        sys_id = self.0.system_id,
+        to = display(to),
+        msg_type = std::any::type_name::<M>()
+    ))]
+    pub async fn send_checked<M>(&self, to: ActorID, message: M) -> Result<(), SendError<M>>
+    where
+        M: Send + 'static,
+    {
+        match self.messages_tx::<M>(to).await {
+            Ok(tx) => tx.send(message).await,
+            Err(reason) => Err(reason.with(message)),
+        }
+    }
+
+    /// Send a single message to the specified actor via its urgent lane.
+    ///
+    /// The actor receives the urgent messages before the regular ones (but after the signals). The
+    /// urgent lane is unbounded: the actor's [`InboxOverflow`](crate::spawn_opts::InboxOverflow)
+    /// policy does not apply to it.
+    ///
+    /// The messages that could not be delivered are passed to the
+    /// [`SystemConfig::dead_letter_handler`](crate::system_config::SystemConfig::dead_letter_handler).
+    #[tracing::instrument(skip_all, fields(
+        sys_id = self.0.system_id,
+        to = display(to),
+        msg_type = std::any::type_name::<M>()
+    ))]
+    pub async fn send_urgent<M>(&self, to: ActorID, message: M)
+    where
+        M: Send + 'static,
+    {
+        let sent = match self.messages_tx::<M>(to).await {
+            Ok(tx) => tx.send_urgent(message),
+            Err(reason) => Err(reason.with(message)),
+        };
+        if let Err(rejected) = sent {
+            tracing::trace!("urgent message not sent: {}", rejected);
+            self.0.config.dead_letter_handler.on_dead_letter(DeadLetter::new(to, rejected));
+        }
+    }
+
+    /// Send a single message to the specified actor without waiting, reporting whether the
+    /// message has been accepted.
+    ///
+    /// Note: with the policies
+    /// [`InboxOverflow::DropNewest`](crate::spawn_opts::InboxOverflow::DropNewest) and
+    /// [`InboxOverflow::DropOldest`](crate::spawn_opts::InboxOverflow::DropOldest) the message
+    /// may still be dropped after it has been accepted.
+    #[tracing::instrument(skip_all, fields(
+        sys_id = self.0.system_id,
+        to = display(to),
+        msg_type = std::any::type_name::<M>()
+    ))]
+    pub async fn try_send<M>(&self, to: ActorID, message: M) -> Result<(), SendError<M>>
+    where
+        M: Send + 'static,
+    {
+        let Some(route) = self.actor_route(to) else { return Err(SendError::NoActor(message)) };
+        let Some(tx) = route.messages_tx::<M>() else {
+            return Err(SendError::InvalidMessageType(message))
+        };
+        tx.try_send(message)
+    }
+
+    async fn messages_tx<M>(&self, to: ActorID) -> Result<MailboxTx<M>, SendError<()>>
+    where
+        M: Send + 'static,
+    {
+        let Some(route) = self.actor_route(to) else {
+            tracing::trace!("no actor_route");
+            return Err(SendError::NoActor(()))
+        };
+        route.messages_tx::<M>().cloned().ok_or_else(|| {
+            tracing::warn!("message-type mismatch");
+            SendError::InvalidMessageType(())
+        })
+    }
+
+    /// Send a single message to the specified actor after the `delay`.
+    ///
+    /// The message is not sent should the actor exit earlier.
+    #[tracing::instrument(skip_all, fields(
+        sys_id = self.0.system_id,
+        to = display(to),
+        msg_type = std::any::type_name::<M>()
+    ))]
+    pub async fn send_after<M>(&self, to: ActorID, delay: Duration, message: M)
+    where
+        M: Send + 'static,
+    {
+        let deadline = Instant::now() + delay;
+        let (exit_tx, exit_rx) = oneshot::channel();
+
+        if let Some(mut entry) = self
+            .actor_entry_write(to)
+            .await
+            .filter(|entry| entry.running_actor_id() == Some(to))
+        {
+            entry.add_watch(exit_tx);
         } else {
-            tracing::trace!("no actor_entry")
+            tracing::trace!("no actor_entry");
+            return
         }
+
+        let system = self.rc_downgrade();
+        self.0.config.spawner.spawn(Box::pin(async move {
+            tokio::select! {
+                biased;
+
+                _ = exit_rx => tracing::trace!("actor exited, timer cancelled"),
+                () = tokio::time::sleep_until(deadline) =>
+                    if let Some(system) = system.rc_upgrade() {
+                        system.send(to, message).await
+                    },
+            }
+        }));
     }
 
     /// Open a channel to the specified actor.
@@ -233,6 +591,11 @@ impl System {
     /// When sending a series of messages to an actor, it may be better from the performance point
     /// of view to open a channel to an actor, rather than sending each message separately using
     /// [`System::send::<Message>(&self, ActorID, Message)`](crate::system::System::send).
+    ///
+    /// The message-types accepted via [adapters](crate::spawn_opts::SpawnOpts::with_adapter) are
+    /// converted upon sending. The actors with a
+    /// [bounded](crate::spawn_opts::InboxOverflow::is_bounded) msg-inbox can only be reached via a
+    /// [`System::bounded_channel`](crate::system::System::bounded_channel).
     #[tracing::instrument(skip_all, fields(
         sys_id = self.0.system_id,
         to = display(to)
@@ -241,11 +604,33 @@ impl System {
     where
         M: Send + 'static,
     {
-        self.actor_entry_read(to)
-            .await
+        let route = self.actor_route(to).ok_or(SysChannelError::NoActor)?;
+        let messages_tx = route.messages_tx().ok_or(SysChannelError::InvalidMessageType)?;
+        if messages_tx.is_bounded() {
+            Err(SysChannelError::BoundedInbox)
+        } else {
+            Ok(ActorChannel(messages_tx.to_owned()))
+        }
+    }
+
+    /// Open a channel to the specified actor, respecting the actor's
+    /// [`InboxOverflow`](crate::spawn_opts::InboxOverflow) policy.
+    #[tracing::instrument(skip_all, fields(
+        sys_id = self.0.system_id,
+        to = display(to)
+    ))]
+    pub async fn bounded_channel<M>(
+        &self,
+        to: ActorID,
+    ) -> Result<BoundedActorChannel<M>, SysChannelError>
+    where
+        M: Send + 'static,
+    {
+        self.actor_route(to)
             .ok_or(SysChannelError::NoActor)?
             .messages_tx()
             .cloned()
+            .map(BoundedActorChannel)
             .ok_or(SysChannelError::InvalidMessageType)
     }
 
@@ -256,14 +641,14 @@ impl System {
         right = display(right)
     ))]
     pub async fn link(&self, left: ActorID, right: ActorID) {
-        let left_accepted_sys_msg = self.send_sys_msg(left, SysMsg::Link(right)).await;
-        let right_accepted_sys_msg = self.send_sys_msg(right, SysMsg::Link(left)).await;
+        let left_accepted_sys_msg = self.send_sys_msg(left, SysMsg::Link(right));
+        let right_accepted_sys_msg = self.send_sys_msg(right, SysMsg::Link(left));
 
         if !right_accepted_sys_msg {
-            self.send_sys_msg(left, SysMsg::SigExit(right, Exit::no_actor())).await;
+            self.send_sys_msg(left, SysMsg::SigExit(right, Exit::no_actor()));
         }
         if !left_accepted_sys_msg {
-            self.send_sys_msg(right, SysMsg::SigExit(left, Exit::no_actor())).await;
+            self.send_sys_msg(right, SysMsg::SigExit(left, Exit::no_actor()));
         }
     }
 
@@ -304,8 +689,12 @@ impl System {
     }
 
     pub fn all_actors(&self) -> impl Stream<Item = ActorID> + '_ {
-        stream::iter(&self.0.actor_entries[..])
-            .filter_map(|slot| async move { slot.read().await.running_actor_id() })
+        stream::iter(
+            self.0
+                .actor_entries
+                .slots()
+                .filter_map(|slot| slot.route.load().as_ref().map(|route| route.actor_id())),
+        )
     }
 
     #[tracing::instrument(skip_all, fields(
@@ -314,7 +703,7 @@ impl System {
     ))]
     pub async fn actor_info(&self, actor_id: ActorID) -> Option<ActorInfo> {
         let (tx, rx) = oneshot::channel();
-        self.send_sys_msg(actor_id, SysMsg::GetInfo(tx)).await;
+        self.send_sys_msg(actor_id, SysMsg::GetInfo(tx));
         rx.await.ok()
     }
 }
@@ -324,6 +713,11 @@ struct Inner {
     config: SystemConfig,
     system_id: usize,
     actor_id_pool: ActorIDPool,
-    actor_entries: Box<[RwLock<ActorEntry>]>,
+    actor_entries: ActorTable,
     exit_handler: Arc<dyn ExitHandler>,
+    shutting_down: AtomicBool,
+    /// held for reading while an actor is registered, and for writing while the shutdown begins
+    spawn_gate: RwLock<()>,
+    events_tx: broadcast::Sender<SystemEvent>,
+    ref_seqs: Arc<RefSeqs>,
 }
@@ -3,10 +3,9 @@ use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::time::Instant;
 
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::oneshot;
 
 use crate::actor_id::ActorID;
-use crate::actor_runner::sys_msg::SysMsg;
 use crate::exit::Exit;
 
 use super::actor_id_pool::ActorIDLease;
@@ -25,8 +24,6 @@ enum Entry {
 #[derive(Debug)]
 struct Occupied {
     actor_id_lease: ActorIDLease,
-    messages_tx: Box<dyn Any + Send + Sync + 'static>,
-    sys_msg_tx: mpsc::UnboundedSender<SysMsg>,
     watches: Vec<oneshot::Sender<Exit>>,
     data: HashMap<TypeId, Data>,
 }
@@ -59,34 +56,12 @@ impl ActorEntry {
             Entry::Vacant(None) => None,
         }
     }
-
-    pub fn messages_tx<M>(&self) -> Option<&mpsc::UnboundedSender<M>>
-    where
-        M: Send + 'static,
-    {
-        self.occupied().and_then(|oe| oe.messages_tx.downcast_ref())
-    }
-    pub fn sys_msg_tx(&self) -> Option<&mpsc::UnboundedSender<SysMsg>> {
-        self.occupied().map(|oe| &oe.sys_msg_tx)
-    }
 }
 
 impl ActorEntry {
-    pub fn new<Message>(
-        actor_id_lease: ActorIDLease,
-        messages_tx: mpsc::UnboundedSender<Message>,
-        sys_msg_tx: mpsc::UnboundedSender<SysMsg>,
-    ) -> Self
-    where
-        Message: Send + 'static,
-    {
-        let occupied = Occupied {
-            actor_id_lease,
-            messages_tx: Box::new(messages_tx),
-            sys_msg_tx,
-            watches: Default::default(),
-            data: Default::default(),
-        };
+    pub fn new(actor_id_lease: ActorIDLease) -> Self {
+        let occupied =
+            Occupied { actor_id_lease, watches: Default::default(), data: Default::default() };
         let entry = Entry::Occupied(occupied);
         Self(entry)
     }
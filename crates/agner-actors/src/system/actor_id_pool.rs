@@ -1,11 +1,23 @@
+use std::collections::VecDeque;
 use std::ops::Deref;
 use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use crate::actor_id::ActorID;
 
-const MARKER_ACQUIRED: usize = usize::MAX;
-
+const RELEASED_SHARDS: usize = 16;
+
+/// The pool of the [`ActorID`]s.
+///
+/// The `actor` component of an id is the index of a slot in the actor-table. The released indices
+/// are reused; when there are none, a fresh index is allocated, so that the actor-table grows.
+///
+/// The released indices are kept in [`RELEASED_SHARDS`] free-lists, each behind its own lock, so
+/// that the actors spawned and exiting concurrently do not contend for a single lock. An index is
+/// released into the shard it maps to; an acquisition starts with the next shard in turn, and
+/// looks through the others only if that one is empty. Within a shard, the indices are reused in
+/// the order they have been released. The `seq` component is unique within the pool, so
+/// that a stale [`ActorID`] does not match the id of an actor occupying the same slot later.
 #[derive(Debug, Clone)]
 pub struct ActorIDPool(Arc<Inner>);
 
@@ -13,9 +25,11 @@ pub struct ActorIDPool(Arc<Inner>);
 struct Inner {
     system_id: usize,
     next_seq_id: AtomicUsize,
-    head: AtomicUsize,
-    tail: AtomicUsize,
-    slots: Box<[AtomicUsize]>,
+    next_slot_idx: AtomicUsize,
+    in_use: AtomicUsize,
+    released: [Mutex<VecDeque<usize>>; RELEASED_SHARDS],
+    released_count: AtomicUsize,
+    next_shard: AtomicUsize,
 }
 
 #[derive(Debug)]
@@ -39,52 +53,39 @@ impl Drop for ActorIDLease {
 
 impl ActorIDPool {
     /// Create a new pool.
-    pub fn new(system_id: usize, max_actors: usize) -> Self {
-        assert!(max_actors + 1 < MARKER_ACQUIRED);
-
+    pub fn new(system_id: usize) -> Self {
         let inner = Arc::new(Inner {
             system_id,
             next_seq_id: AtomicUsize::new(0),
-            head: AtomicUsize::new(0),
-            tail: AtomicUsize::new(max_actors),
-            slots: (0..max_actors)
-                .map(AtomicUsize::new)
-                .chain(std::iter::once(AtomicUsize::new(MARKER_ACQUIRED)))
-                .collect(),
+            next_slot_idx: AtomicUsize::new(0),
+            in_use: AtomicUsize::new(0),
+            released: Default::default(),
+            released_count: AtomicUsize::new(0),
+            next_shard: AtomicUsize::new(0),
         });
 
         Self(inner)
     }
 
-    /// Acquire an unused [`ActorID`]
-    pub fn acquire_id(&self) -> Option<ActorIDLease> {
-        let slot_idx = self.0.take_nonempty_slot()?;
-        let slot = &self.0.slots[slot_idx];
-
-        let actor_id = loop {
-            let actor_id = slot.load(AtomicOrdering::Relaxed);
-            if actor_id == MARKER_ACQUIRED {
-                continue
-            }
-            let actor_id = slot
-                .compare_exchange(
-                    actor_id,
-                    MARKER_ACQUIRED,
-                    AtomicOrdering::Relaxed,
-                    AtomicOrdering::Relaxed,
-                )
-                .expect("Concurrent modification of non-empty slot");
-            break actor_id
-        };
-
+    /// Acquire an unused [`ActorID`], along with the number of the ids leased at the moment (this
+    /// one included).
+    pub fn acquire_id(&self) -> (ActorIDLease, usize) {
+        let in_use = self.0.in_use.fetch_add(1, AtomicOrdering::SeqCst) + 1;
+        let slot_idx = self.0.take_released_slot().unwrap_or_else(|| self.0.take_fresh_slot());
         let seq_id = self.0.next_seq_id();
 
-        let in_use = ActorIDLease {
+        let lease = ActorIDLease {
             inner: Arc::clone(&self.0),
-            actor_id: ActorID::new(self.0.system_id, actor_id, seq_id),
+            actor_id: ActorID::new(self.0.system_id, slot_idx, seq_id),
         };
 
-        Some(in_use)
+        (lease, in_use)
+    }
+
+    /// The number of ids currently leased.
+    #[cfg(test)]
+    pub fn in_use(&self) -> usize {
+        self.0.in_use.load(AtomicOrdering::Relaxed)
     }
 }
 
@@ -93,82 +94,41 @@ impl Inner {
         self.next_seq_id.fetch_add(1, AtomicOrdering::Relaxed)
     }
 
-    fn take_nonempty_slot(&self) -> Option<usize> {
-        loop {
-            if let Ok(ret) = self.take_nonempty_slot_inner() {
-                break ret
-            }
+    fn take_released_slot(&self) -> Option<usize> {
+        // spares the look through all the shards while the table is growing
+        if self.released_count.load(AtomicOrdering::SeqCst) == 0 {
+            return None
         }
-    }
-    fn take_nonempty_slot_inner(&self) -> Result<Option<usize>, ()> {
-        let head = self.head.load(AtomicOrdering::SeqCst);
-        let tail = self.tail.load(AtomicOrdering::SeqCst);
-
-        let last = self.slots.len() - 1;
-        let head_next = if head < last { head + 1 } else { 0 };
-
-        if head == tail {
-            Ok(None)
-        } else {
-            let idx = self
-                .head
-                .compare_exchange(head, head_next, AtomicOrdering::SeqCst, AtomicOrdering::SeqCst)
-                .map_err(|_| ())?;
-            Ok(Some(idx))
-        }
-    }
 
-    fn take_empty_slot(&self) -> Option<usize> {
-        loop {
-            if let Ok(ret) = self.take_empty_slot_inner() {
-                break ret
-            }
-        }
+        let first = self.next_shard.fetch_add(1, AtomicOrdering::Relaxed);
+        let slot_idx = (0..RELEASED_SHARDS).find_map(|i| {
+            self.released[(first + i) % RELEASED_SHARDS]
+                .lock()
+                .expect("poisoned mutex")
+                .pop_front()
+        })?;
+        self.released_count.fetch_sub(1, AtomicOrdering::SeqCst);
+        Some(slot_idx)
     }
 
-    fn take_empty_slot_inner(&self) -> Result<Option<usize>, ()> {
-        let head = self.head.load(AtomicOrdering::SeqCst);
-        let tail = self.tail.load(AtomicOrdering::SeqCst);
-
-        let last = self.slots.len() - 1;
-        let tail_next = if tail < last { tail + 1 } else { 0 };
-
-        if tail_next == head {
-            Ok(None)
-        } else {
-            let idx = self
-                .tail
-                .compare_exchange(tail, tail_next, AtomicOrdering::SeqCst, AtomicOrdering::SeqCst)
-                .map_err(|_| ())?;
-            Ok(Some(idx))
-        }
+    fn take_fresh_slot(&self) -> usize {
+        self.next_slot_idx.fetch_add(1, AtomicOrdering::Relaxed)
     }
 
-    fn release_id(&self, actor_id: usize) {
-        let slot_idx = self
-            .take_empty_slot()
-            .expect("An attempt to release an id into already full pool.");
-        let slot = &self.slots[slot_idx];
-
-        loop {
-            let value_before = slot.load(AtomicOrdering::SeqCst);
-            if value_before != MARKER_ACQUIRED {
-                continue
-            }
-            slot.compare_exchange(
-                value_before,
-                actor_id,
-                AtomicOrdering::SeqCst,
-                AtomicOrdering::SeqCst,
-            )
-            .expect("Concurrent modification of empty slot");
-            break
-        }
+    fn release_id(&self, slot_idx: usize) {
+        // counted in advance: the count is never less than the number of the released indices
+        self.released_count.fetch_add(1, AtomicOrdering::SeqCst);
+        self.released[slot_idx % RELEASED_SHARDS]
+            .lock()
+            .expect("poisoned mutex")
+            .push_back(slot_idx);
+        self.in_use.fetch_sub(1, AtomicOrdering::SeqCst);
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
     use std::time::Duration;
 
     use futures::StreamExt;
@@ -176,72 +136,79 @@ mod tests {
     use super::*;
 
     #[test]
-    fn acquire_till_out_of_ids_release_and_retry() {
-        let pool = ActorIDPool::new(1, 3);
+    fn acquire_release_and_reacquire() {
+        let pool = ActorIDPool::new(1);
 
-        let id_1 = pool.acquire_id().expect("out of ids");
-        eprintln!("acquired: {}", *id_1);
-        let id_2 = pool.acquire_id().expect("out of ids");
-        eprintln!("acquired: {}", *id_2);
-        let id_3 = pool.acquire_id().expect("out of ids");
-        eprintln!("acquired: {}", *id_3);
-        assert!(pool.acquire_id().is_none());
+        let (id_1, in_use) = pool.acquire_id();
+        assert_eq!(in_use, 1);
+        let (id_2, in_use) = pool.acquire_id();
+        assert_eq!(in_use, 2);
+        let (id_3, in_use) = pool.acquire_id();
+        assert_eq!(in_use, 3);
 
+        let released = *id_2;
         std::mem::drop(id_2);
 
-        let id_4 = pool.acquire_id().expect("out of ids");
-        eprintln!("acquired: {}", *id_4);
+        let (id_4, in_use) = pool.acquire_id();
+        assert_eq!(in_use, 3);
+        assert_eq!(id_4.actor(), released.actor());
+        assert_ne!(*id_4, released);
+
+        std::mem::drop((id_1, id_3, id_4));
+        assert_eq!(pool.in_use(), 0);
+    }
+
+    #[test]
+    fn pool_grows_and_reuses_released_ids() {
+        let pool = ActorIDPool::new(1);
+
+        let leases = (0..1000).map(|_| pool.acquire_id().0).collect::<Vec<_>>();
+        assert_eq!(pool.in_use(), 1000);
+
+        let released = leases.iter().map(|lease| **lease).take(10).collect::<Vec<_>>();
+        let leases = leases.into_iter().skip(10).collect::<Vec<_>>();
+        assert_eq!(pool.in_use(), 990);
+
+        let reacquired = released.iter().map(|_| pool.acquire_id().0).collect::<Vec<_>>();
+        assert_eq!(
+            reacquired.iter().map(|lease| lease.actor()).collect::<BTreeSet<_>>(),
+            released.iter().map(ActorID::actor).collect::<BTreeSet<_>>(),
+        );
+        assert!(reacquired.iter().all(|lease| !released.contains(lease)));
 
-        std::mem::drop(id_3);
-        let id_5 = pool.acquire_id().expect("out of ids");
-        eprintln!("acquired: {}", *id_5);
+        let (fresh, _) = pool.acquire_id();
+        assert_eq!(fresh.actor(), 1000);
+
+        std::mem::drop((leases, reacquired, fresh));
+        assert_eq!(pool.in_use(), 0);
     }
 
     #[tokio::test]
     async fn concurrently_acquired_ids() {
         const ATTEMPTS: usize = 1_000_000;
         const CONCURRENCY: usize = 1024;
-        const MAX_ACTORS: usize = 512;
-
-        let pool = ActorIDPool::new(2, MAX_ACTORS);
-
-        let (total_attempts, total_retries, max_retries, max_id) =
-            futures::stream::iter(0..ATTEMPTS)
-                .map(|attempt_id| {
-                    let pool = pool.to_owned();
-                    async move {
-                        let mut retries = 0;
-                        let actor_id = loop {
-                            if let Some(id) = pool.acquire_id() {
-                                tokio::time::sleep(Duration::ZERO).await;
-                                break id
-                            } else {
-                                retries += 1;
-                                tokio::time::sleep(Duration::ZERO).await
-                            }
-                        };
-                        // eprintln!("[{}] {:?} @ {:?}", attempt_id, *actor_id, retries);
-                        (attempt_id, retries, *actor_id)
-                    }
-                })
-                .buffer_unordered(CONCURRENCY)
-                .fold(
-                    (0, 0, 0, ActorID::new(0, 0, 0)),
-                    |(total_attempts, total_retries, max_retries, max_id),
-                     (_attempt_id, retries, actor_id)| async move {
-                        (
-                            total_attempts + 1,
-                            total_retries + retries,
-                            std::cmp::max(max_retries, retries),
-                            std::cmp::max(max_id, actor_id),
-                        )
-                    },
-                )
-                .await;
+
+        let pool = ActorIDPool::new(2);
+
+        let (total_attempts, max_id) = futures::stream::iter(0..ATTEMPTS)
+            .map(|_attempt_id| {
+                let pool = pool.to_owned();
+                async move {
+                    let (actor_id, _) = pool.acquire_id();
+                    tokio::time::sleep(Duration::ZERO).await;
+                    *actor_id
+                }
+            })
+            .buffer_unordered(CONCURRENCY)
+            .fold((0, ActorID::new(0, 0, 0)), |(total_attempts, max_id), actor_id| async move {
+                (total_attempts + 1, std::cmp::max(max_id, actor_id))
+            })
+            .await;
+
+        assert_eq!(total_attempts, ATTEMPTS);
+        assert_eq!(pool.in_use(), 0);
 
         eprintln!("total-attempts: {:?}", total_attempts);
-        eprintln!("total-retries:  {:?}", total_retries);
-        eprintln!("max-retries:    {:?}", max_retries);
         eprintln!("max-id:         {}", max_id);
     }
 }
@@ -0,0 +1,67 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::{mpsc, watch};
+
+use crate::actor_id::ActorID;
+use crate::actor_runner::sys_msg::SysMsg;
+use crate::exit::Exit;
+use crate::mailbox::{AdaptedMailboxTx, MailboxTx};
+
+/// The channels to a running actor.
+///
+/// A route is never modified in place: adding an adapter produces a new route, that replaces the
+/// previous one in the actor's slot. Thus the route can be read without locking the
+/// [`ActorEntry`](super::actor_entry::ActorEntry).
+#[derive(Debug, Clone)]
+pub struct ActorRoute {
+    actor_id: ActorID,
+    messages_tx: Arc<dyn Any + Send + Sync + 'static>,
+    adapters: HashMap<TypeId, AdaptedMailboxTx>,
+    sys_msg_tx: mpsc::UnboundedSender<SysMsg>,
+    exited: watch::Receiver<Option<Exit>>,
+}
+
+impl ActorRoute {
+    pub fn new<Message>(
+        actor_id: ActorID,
+        messages_tx: MailboxTx<Message>,
+        adapters: HashMap<TypeId, AdaptedMailboxTx>,
+        sys_msg_tx: mpsc::UnboundedSender<SysMsg>,
+        exited: watch::Receiver<Option<Exit>>,
+    ) -> Self
+    where
+        Message: Send + 'static,
+    {
+        Self { actor_id, messages_tx: Arc::new(messages_tx), adapters, sys_msg_tx, exited }
+    }
+
+    pub fn actor_id(&self) -> ActorID {
+        self.actor_id
+    }
+
+    pub fn messages_tx<M>(&self) -> Option<&MailboxTx<M>>
+    where
+        M: Send + 'static,
+    {
+        self.messages_tx
+            .downcast_ref()
+            .or_else(|| self.adapters.get(&TypeId::of::<M>()).and_then(|tx| tx.downcast_ref()))
+    }
+
+    pub fn sys_msg_tx(&self) -> &mpsc::UnboundedSender<SysMsg> {
+        &self.sys_msg_tx
+    }
+
+    /// The exit reason of the actor, published as soon as the actor starts exiting.
+    pub fn exited(&self) -> watch::Receiver<Option<Exit>> {
+        self.exited.to_owned()
+    }
+
+    pub fn with_adapter(&self, type_id: TypeId, adapted_tx: AdaptedMailboxTx) -> Self {
+        let mut route = self.to_owned();
+        route.adapters.insert(type_id, adapted_tx);
+        route
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::OnceLock;
+
+use arc_swap::ArcSwapOption;
+use tokio::sync::RwLock;
+
+use super::actor_entry::ActorEntry;
+use super::actor_route::ActorRoute;
+
+const FIRST_SEGMENT_SIZE_BITS: u32 = 6;
+const FIRST_SEGMENT_SIZE: usize = 1 << FIRST_SEGMENT_SIZE_BITS;
+const SEGMENTS_COUNT: usize = (usize::BITS - FIRST_SEGMENT_SIZE_BITS) as usize;
+
+/// The table of the actor-entries, growing on demand.
+///
+/// The table consists of segments, each twice the size of the previous one. A segment is
+/// allocated when a slot in it is accessed for the first time, and is never moved or deallocated
+/// until the table is dropped: thus the references to the slots remain valid as the table grows.
+#[derive(Debug)]
+pub(crate) struct ActorTable {
+    segments: Box<[OnceLock<Segment>]>,
+}
+
+type Segment = Box<[Slot]>;
+
+/// A slot of the table.
+///
+/// The `route` of a running actor is read without locking (e.g. when sending a message to that
+/// actor); the `entry` is locked when the watches or the data associated with the actor are
+/// accessed.
+#[derive(Debug, Default)]
+pub(crate) struct Slot {
+    pub route: ArcSwapOption<ActorRoute>,
+    pub entry: RwLock<ActorEntry>,
+}
+
+impl ActorTable {
+    pub fn new() -> Self {
+        Self { segments: (0..SEGMENTS_COUNT).map(|_| OnceLock::new()).collect() }
+    }
+
+    /// The slot with the specified index, unless its segment has not been allocated yet (then
+    /// the slot has never been occupied).
+    pub fn get(&self, slot_idx: usize) -> Option<&Slot> {
+        let (segment_idx, offset) = locate(slot_idx);
+        self.segments[segment_idx].get().map(|segment| &segment[offset])
+    }
+
+    /// The slot with the specified index, allocating its segment if necessary.
+    pub fn slot(&self, slot_idx: usize) -> &Slot {
+        let (segment_idx, offset) = locate(slot_idx);
+        let segment = self.segments[segment_idx].get_or_init(|| {
+            (0..FIRST_SEGMENT_SIZE << segment_idx).map(|_| Default::default()).collect()
+        });
+        &segment[offset]
+    }
+
+    /// All the allocated slots.
+    pub fn slots(&self) -> impl Iterator<Item = &Slot> {
+        self.segments
+            .iter()
+            .filter_map(OnceLock::get)
+            .flat_map(|segment| segment.iter())
+    }
+}
+
+/// The index of the segment, and the offset within that segment, of the slot `slot_idx`.
+fn locate(slot_idx: usize) -> (usize, usize) {
+    let n = slot_idx / FIRST_SEGMENT_SIZE + 1;
+    let segment_idx = (usize::BITS - 1 - n.leading_zeros()) as usize;
+    let segment_start = FIRST_SEGMENT_SIZE * ((1 << segment_idx) - 1);
+    (segment_idx, slot_idx - segment_start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_are_located_in_growing_segments() {
+        assert_eq!(locate(0), (0, 0));
+        assert_eq!(locate(FIRST_SEGMENT_SIZE - 1), (0, FIRST_SEGMENT_SIZE - 1));
+        assert_eq!(locate(FIRST_SEGMENT_SIZE), (1, 0));
+        assert_eq!(locate(FIRST_SEGMENT_SIZE * 3 - 1), (1, FIRST_SEGMENT_SIZE * 2 - 1));
+        assert_eq!(locate(FIRST_SEGMENT_SIZE * 3), (2, 0));
+
+        for slot_idx in 0..FIRST_SEGMENT_SIZE * 100 {
+            let (segment_idx, offset) = locate(slot_idx);
+            assert!(offset < FIRST_SEGMENT_SIZE << segment_idx);
+        }
+    }
+
+    #[test]
+    fn segments_are_allocated_on_demand() {
+        let table = ActorTable::new();
+        assert!(table.get(0).is_none());
+        assert_eq!(table.slots().count(), 0);
+
+        let _ = table.slot(0);
+        assert_eq!(table.slots().count(), FIRST_SEGMENT_SIZE);
+        assert!(table.get(FIRST_SEGMENT_SIZE - 1).is_some());
+        assert!(table.get(FIRST_SEGMENT_SIZE).is_none());
+        assert_eq!(table.slots().count(), FIRST_SEGMENT_SIZE);
+
+        let _ = table.slot(FIRST_SEGMENT_SIZE);
+        assert_eq!(table.slots().count(), FIRST_SEGMENT_SIZE * 3);
+
+        assert!(std::ptr::eq(table.slot(1), table.slot(1)));
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use agner_utils::future_timeout_ext::FutureTimeoutExt;
+use tokio::sync::oneshot;
+
+use super::*;
+
+/// A handle used by the callee to reply to a [call](crate::system::System::call).
+#[derive(Debug)]
+pub struct ReplyTo<R>(oneshot::Sender<R>);
+
+impl<R> ReplyTo<R> {
+    /// Send the reply to the caller.
+    ///
+    /// Returns the reply back if the caller is no longer waiting for it.
+    pub fn reply(self, reply: R) -> Result<(), R> {
+        self.0.send(reply)
+    }
+
+    /// Whether the caller has stopped waiting for the reply (e.g. due to a timeout).
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+}
+
+impl System {
+    /// Send a request to the specified actor and wait for the reply.
+    ///
+    /// The request is constructed by `make_request` from the provided [`ReplyTo<R>`]. The call
+    /// fails:
+    /// - with [`CallError::NoActor`] if there is no such actor;
+    /// - with [`CallError::InvalidMessageType`] if the actor does not accept messages of type `M`;
+    /// - with [`CallError::CalleeExited`] as soon as the callee terminates without replying;
+    /// - with [`CallError::NoReply`] as soon as the callee drops the [`ReplyTo<R>`] without
+    ///   replying;
+    /// - with [`CallError::Timeout`] if the reply has not arrived within `timeout`.
+    ///
+    /// Example:
+    /// ```
+    /// use std::time::Duration;
+    /// use agner_actors::{Context, ReplyTo, System};
+    ///
+    /// async fn doubler(context: &mut Context<(usize, ReplyTo<usize>)>, _args: ()) {
+    ///     loop {
+    ///         let (n, reply_to) = context.next_message().await;
+    ///         let _ = reply_to.reply(n * 2);
+    ///     }
+    /// }
+    ///
+    /// let _ = async {
+    ///     let system = System::new(Default::default());
+    ///     let actor = system.spawn(doubler, (), Default::default()).await.unwrap();
+    ///
+    ///     let four: usize = system
+    ///         .call(actor, |reply_to| (2, reply_to), Duration::from_secs(1))
+    ///         .await
+    ///         .expect("call failed");
+    ///     assert_eq!(four, 4);
+    /// };
+    /// ```
+    #[tracing::instrument(skip_all, fields(
+        sys_id = self.0.system_id,
+        to = display(to),
+        msg_type = std::any::type_name::<M>(),
+        reply_type = std::any::type_name::<R>(),
+    ))]
+    pub async fn call<M, R, F>(
+        &self,
+        to: ActorID,
+        make_request: F,
+        timeout: Duration,
+    ) -> Result<R, CallError>
+    where
+        M: Send + 'static,
+        R: Send + 'static,
+        F: FnOnce(ReplyTo<R>) -> M,
+    {
+        let (reply_tx, reply_rx) = oneshot::channel();
+
+        let route = self.actor_route(to).ok_or(CallError::NoActor)?;
+        let messages_tx = route.messages_tx::<M>().cloned().ok_or(CallError::InvalidMessageType)?;
+        let mut exited = route.exited();
+        std::mem::drop(route);
+
+        let outcome = async {
+            // should the callee's inbox be already closed — its exit reason is already published.
+            if let Err(SendError::Full(_)) = messages_tx.send(make_request(ReplyTo(reply_tx))).await
+            {
+                return Err(CallError::InboxFull)
+            }
+
+            let replied = tokio::select! {
+                biased;
+
+                reply = reply_rx => Some(reply),
+                _ = exited.wait_for(Option::is_some) => None,
+            };
+            let exit_reason = exited.borrow().to_owned();
+            match (replied, exit_reason) {
+                (Some(Ok(reply)), _) => Ok(reply),
+                // the callee publishes its exit reason before dropping the pending requests
+                (Some(Err(_)), None) => Err(CallError::NoReply),
+                (_, exit_reason) =>
+                    Err(CallError::CalleeExited(exit_reason.unwrap_or_else(Exit::no_actor))),
+            }
+        };
+
+        outcome.timeout(timeout).await.map_err(|_elapsed| CallError::Timeout)?
+    }
+}
@@ -1,8 +1,15 @@
+use std::fmt;
+
+use crate::exit::Exit;
+
 /// A failure to spawn an actor by [`System::spawn(&self, ...)`](crate::system::System::spawn).
 #[derive(Debug, thiserror::Error)]
 pub enum SysSpawnError {
-    #[error("No available IDs (max_actors limit reached)")]
-    MaxActorsLimit,
+    #[error("The system is shutting down")]
+    ShuttingDown,
+
+    #[error("Message adapter for {} does not convert into the actor's message-type", _0)]
+    InvalidAdapter(&'static str),
 }
 
 /// An failure to open a channel to an actor (see [`System::channel::<Message>(&self,
@@ -14,4 +21,74 @@ pub enum SysChannelError {
 
     #[error("Invalid message-type")]
     InvalidMessageType,
+
+    #[error("The actor's msg-inbox is bounded (see `System::bounded_channel`)")]
+    BoundedInbox,
+}
+
+/// A failure to send a message to an actor (see [`System::try_send(&self, ActorID,
+/// Message)`](crate::system::System::try_send)).
+///
+/// The rejected message is returned back to the sender.
+#[derive(thiserror::Error)]
+pub enum SendError<M> {
+    #[error("No such actor")]
+    NoActor(M),
+
+    #[error("Invalid message-type")]
+    InvalidMessageType(M),
+
+    #[error("Inbox full")]
+    Full(M),
+}
+
+/// A failure of a [`System::call(&self, ActorID, ...)`](crate::system::System::call).
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CallError {
+    #[error("No such actor")]
+    NoActor,
+
+    #[error("Invalid message-type")]
+    InvalidMessageType,
+
+    #[error("Timeout")]
+    Timeout,
+
+    #[error("Callee's inbox is full")]
+    InboxFull,
+
+    #[error("Callee exited")]
+    CalleeExited(#[source] Exit),
+
+    #[error("Callee dropped the request without replying")]
+    NoReply,
+}
+
+impl<M> SendError<M> {
+    /// Take the rejected message back.
+    pub fn into_inner(self) -> M {
+        match self {
+            Self::NoActor(m) | Self::InvalidMessageType(m) | Self::Full(m) => m,
+        }
+    }
+}
+
+impl SendError<()> {
+    pub(crate) fn with<M>(self, message: M) -> SendError<M> {
+        match self {
+            Self::NoActor(()) => SendError::NoActor(message),
+            Self::InvalidMessageType(()) => SendError::InvalidMessageType(message),
+            Self::Full(()) => SendError::Full(message),
+        }
+    }
+}
+
+impl<M> fmt::Debug for SendError<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoActor(_) => f.write_str("NoActor(..)"),
+            Self::InvalidMessageType(_) => f.write_str("InvalidMessageType(..)"),
+            Self::Full(_) => f.write_str("Full(..)"),
+        }
+    }
 }
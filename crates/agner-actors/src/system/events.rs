@@ -0,0 +1,58 @@
+use tokio::sync::broadcast;
+
+use super::*;
+
+/// A notification about a change in the [`System`](crate::system::System).
+///
+/// See [`System::subscribe_events`](crate::system::System::subscribe_events).
+#[derive(Debug, Clone)]
+pub enum SystemEvent {
+    /// An actor has been spawned
+    Spawned { actor_id: ActorID, behaviour: &'static str },
+
+    /// An actor has exited
+    Exited { actor_id: ActorID, exit_reason: Exit },
+
+    /// The actor `actor_id` has been linked to the actor `to`
+    Linked { actor_id: ActorID, to: ActorID },
+
+    /// The actor `actor_id` has been unlinked from the actor `from`
+    Unlinked { actor_id: ActorID, from: ActorID },
+
+    /// The actor has changed its `trap_exit` flag
+    TrapExit { actor_id: ActorID, trap_exit: bool },
+
+    /// The spawn of the actor `actor_id` has taken the number of the actors in the system beyond
+    /// [`SystemConfig::max_actors`](crate::system_config::SystemConfig::max_actors)
+    MaxActorsExceeded { actor_id: ActorID, max_actors: usize },
+
+    /// The subscriber has not kept up: `missed` events have been skipped
+    Lagged { missed: u64 },
+}
+
+impl System {
+    /// Subscribe to the [events](crate::system::SystemEvent) occurring in this system.
+    ///
+    /// The events are buffered up to
+    /// [`SystemConfig::events_buffer_size`](crate::system_config::SystemConfig::events_buffer_size).
+    /// Should a subscriber fall behind, the oldest events are skipped and
+    /// [`SystemEvent::Lagged`] is yielded in their place.
+    ///
+    /// The stream ends when the system is dropped.
+    pub fn subscribe_events(&self) -> impl Stream<Item = SystemEvent> + Send + 'static {
+        use broadcast::error::RecvError;
+
+        stream::unfold(self.0.events_tx.subscribe(), |mut events_rx| async move {
+            match events_rx.recv().await {
+                Ok(event) => Some((event, events_rx)),
+                Err(RecvError::Lagged(missed)) => Some((SystemEvent::Lagged { missed }, events_rx)),
+                Err(RecvError::Closed) => None,
+            }
+        })
+    }
+
+    pub(crate) fn publish_event(&self, event: SystemEvent) {
+        // an error here only means that there are no subscribers at the moment
+        let _ = self.0.events_tx.send(event);
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::monitor::MonitorRef;
+use crate::timer::TimerRef;
+
+/// The sequences of the [`MonitorRef`]s and the [`TimerRef`]s issued within a
+/// [`System`](crate::system::System).
+///
+/// The refs are numbered per system (rather than per process): thus the refs issued within a
+/// [`System`](crate::system::System) depend only on the order in which its actors install
+/// monitors and start timers. Each actor's [`Context`](crate::context::Context) shares the
+/// sequences, so that it issues the refs without reaching for the system (which may be gone).
+#[derive(Debug)]
+pub(crate) struct RefSeqs {
+    monitor: AtomicUsize,
+    timer: AtomicUsize,
+}
+
+impl RefSeqs {
+    pub fn new() -> Self {
+        Self { monitor: AtomicUsize::new(1), timer: AtomicUsize::new(1) }
+    }
+
+    /// A new [`MonitorRef`], unique within the system.
+    pub fn new_monitor_ref(&self) -> MonitorRef {
+        MonitorRef::new(self.monitor.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// A new [`TimerRef`], unique within the system.
+    pub fn new_timer_ref(&self) -> TimerRef {
+        TimerRef::new(self.timer.fetch_add(1, Ordering::Relaxed))
+    }
+}
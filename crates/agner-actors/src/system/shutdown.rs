@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use futures::StreamExt;
+use tokio::time::Instant;
+
+use crate::actor_id::ActorID;
+use crate::exit::Exit;
+use crate::parent_actor::ParentActor;
+
+use super::System;
+
+/// The outcome of [`System::shutdown`](crate::system::System::shutdown).
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownReport {
+    /// The top-level actors that have been asked to exit.
+    pub top_level: Vec<ActorID>,
+
+    /// The actors that had not terminated within the timeout, and therefore have been killed.
+    pub killed: Vec<ActorID>,
+
+    /// The killed actors that had not terminated within the
+    /// [`actor_termination_timeout`](crate::system_config::SystemConfig::actor_termination_timeout)
+    /// either, and are left running.
+    pub unresponsive: Vec<ActorID>,
+}
+
+impl ShutdownReport {
+    /// Whether all the actors have terminated within the timeout.
+    pub fn is_clean(&self) -> bool {
+        self.killed.is_empty()
+    }
+}
+
+impl System {
+    /// Stop all the actors in this system.
+    ///
+    /// The top-level actors (those without a [`ParentActor`](crate::parent_actor::ParentActor))
+    /// are sent the exit-signal with the `reason` first, so that the supervisors could stop their
+    /// children in an orderly fashion. The actors that are still running once the `timeout`
+    /// elapses are killed; those that have not terminated after being killed, within the
+    /// [`actor_termination_timeout`](crate::system_config::SystemConfig::actor_termination_timeout),
+    /// are reported as [unresponsive](ShutdownReport::unresponsive).
+    ///
+    /// Once this method is invoked, the system does not accept new actors:
+    /// [`System::spawn`](crate::system::System::spawn) fails with
+    /// [`SysSpawnError::ShuttingDown`](crate::system::SysSpawnError::ShuttingDown).
+    #[tracing::instrument(skip_all, fields(sys_id = self.0.system_id))]
+    pub async fn shutdown(&self, reason: Exit, timeout: Duration) -> ShutdownReport {
+        let deadline = Instant::now() + timeout;
+        {
+            let _spawn_gate = self.0.spawn_gate.write().await;
+            self.0.shutting_down.store(true, Ordering::SeqCst);
+        }
+
+        let mut report = ShutdownReport::default();
+
+        let running = self.all_actors().collect::<Vec<_>>().await;
+        let running_set = running.iter().copied().collect::<HashSet<_>>();
+        for actor_id in running.iter().copied() {
+            let parent_opt = self.get_data::<ParentActor>(actor_id).await;
+            if parent_opt.map(|p| !running_set.contains(&p.0)).unwrap_or(true) {
+                report.top_level.push(actor_id);
+            }
+        }
+        tracing::trace!("stopping top-level actors: {:?}", report.top_level);
+
+        for actor_id in report.top_level.iter().copied() {
+            self.exit(actor_id, reason.to_owned()).await;
+        }
+        let _ = tokio::time::timeout_at(deadline, self.wait_all(running)).await;
+
+        report.killed = self.all_actors().collect().await;
+        if !report.killed.is_empty() {
+            tracing::warn!("killing the actors that have not terminated: {:?}", report.killed);
+        }
+        for actor_id in report.killed.iter().copied() {
+            self.exit(actor_id, Exit::kill()).await;
+        }
+        let kill_timeout = self.config().actor_termination_timeout;
+        if tokio::time::timeout(kill_timeout, self.wait_all(report.killed.to_owned()))
+            .await
+            .is_err()
+        {
+            report.unresponsive = self.all_actors().collect().await;
+            tracing::error!("actors not terminated after being killed: {:?}", report.unresponsive);
+        }
+
+        report
+    }
+
+    /// Whether [`System::shutdown`](crate::system::System::shutdown) has been invoked.
+    pub fn is_shutting_down(&self) -> bool {
+        self.0.shutting_down.load(Ordering::SeqCst)
+    }
+
+    async fn wait_all(&self, actors: Vec<ActorID>) {
+        futures::future::join_all(actors.into_iter().map(|actor_id| self.wait(actor_id))).await;
+    }
+}
@@ -1,3 +1,4 @@
+use std::any::TypeId;
 use std::ops::{Deref, DerefMut};
 
 use agner_utils::std_error_pp::StdErrorPP;
@@ -5,7 +6,7 @@ use agner_utils::std_error_pp::StdErrorPP;
 use super::*;
 
 impl System {
-    pub(crate) async fn actor_entry_put(&self, entry: ActorEntry) {
+    pub(crate) async fn actor_entry_put(&self, entry: ActorEntry, route: ActorRoute) {
         let actor_id =
             entry.running_actor_id().expect("Attempt to insert a non-running actor-entry");
         assert_eq!(
@@ -13,16 +14,22 @@ impl System {
             "attempt to insert an entry with a foreign actor-id [this-system-id: {}; entry-system-id: {}]",
             self.0.system_id, actor_id.system());
 
-        let slot_idx = actor_id.actor();
-        let mut slot = self.0.actor_entries[slot_idx].write().await;
-        let should_be_none = std::mem::replace(&mut *slot, entry);
+        assert_eq!(route.actor_id(), actor_id, "the route does not match the actor-entry");
+
+        // the only place where the table grows
+        let slot = self.0.actor_entries.slot(actor_id.actor());
+        let mut locked = slot.entry.write().await;
+        let should_be_none = std::mem::replace(&mut *locked, entry);
         assert!(should_be_none.running_actor_id().is_none());
+        slot.route.store(Some(Arc::new(route)));
     }
 
-    pub(crate) fn actor_entry_slot(&self, actor_id: ActorID) -> &RwLock<ActorEntry> {
+    /// The slot of the actor-table for the specified actor-id (`None` if no actor has ever
+    /// occupied it).
+    pub(crate) fn actor_entry_slot(&self, actor_id: ActorID) -> Option<&Slot> {
         if actor_id.system() != self.0.system_id {
             panic!(
-                "attempt to terminate an entry with a foreign actor-id [this-system-id: {}; entry-system-id: {}]",
+                "attempt to look up an entry with a foreign actor-id [this-system-id: {}; entry-system-id: {}]",
                 self.0.system_id,
                 actor_id.system()
             )
@@ -30,14 +37,14 @@ impl System {
 
         let slot_idx = actor_id.actor();
 
-        &self.0.actor_entries[slot_idx]
+        self.0.actor_entries.get(slot_idx)
     }
 
     pub(crate) async fn actor_entry_read(
         &self,
         actor_id: ActorID,
     ) -> Option<impl Deref<Target = ActorEntry> + '_> {
-        let locked = self.actor_entry_slot(actor_id).read().await;
+        let locked = self.actor_entry_slot(actor_id)?.entry.read().await;
         if locked.running_or_terminated_actor_id() == Some(actor_id) {
             Some(locked)
         } else {
@@ -48,7 +55,7 @@ impl System {
         &self,
         actor_id: ActorID,
     ) -> Option<impl DerefMut<Target = ActorEntry> + '_> {
-        let locked = self.actor_entry_slot(actor_id).write().await;
+        let locked = self.actor_entry_slot(actor_id)?.entry.write().await;
         if locked.running_or_terminated_actor_id() == Some(actor_id) {
             Some(locked)
         } else {
@@ -57,13 +64,73 @@ impl System {
     }
 
     pub(crate) async fn actor_entry_terminate(&self, actor_id: ActorID, exit_reason: Exit) {
-        if let Err(reason) = self
-            .actor_entry_write(actor_id)
-            .await
-            .map(|mut ae| ae.terminate(actor_id, exit_reason))
-            .transpose()
-        {
-            tracing::error!("Failed to terminate ActorEntry: {}", reason.as_ref().pp());
+        if let Some(entry) = self.actor_entry_write(actor_id).await {
+            self.actor_entry_terminate_locked(entry, actor_id, exit_reason);
+        }
+    }
+
+    /// Terminate the actor-entry without waiting for the lock on it: should the entry be locked at
+    /// the moment, the termination is carried out by a task run by the system's spawner.
+    pub(crate) fn actor_entry_terminate_now(&self, actor_id: ActorID, exit_reason: Exit) {
+        let Some(slot) = self.actor_entry_slot(actor_id) else { return };
+        match slot.entry.try_write() {
+            Ok(entry) =>
+                if entry.running_or_terminated_actor_id() == Some(actor_id) {
+                    self.actor_entry_terminate_locked(entry, actor_id, exit_reason)
+                },
+            Err(_locked) => {
+                let system = self.to_owned();
+                self.0.config.spawner.spawn(Box::pin(async move {
+                    system.actor_entry_terminate(actor_id, exit_reason).await
+                }));
+            },
+        }
+    }
+
+    fn actor_entry_terminate_locked(
+        &self,
+        mut entry: impl DerefMut<Target = ActorEntry>,
+        actor_id: ActorID,
+        exit_reason: Exit,
+    ) {
+        self.actor_route_remove(actor_id);
+        match entry.terminate(actor_id, exit_reason.to_owned()) {
+            Ok(()) => self.publish_event(SystemEvent::Exited { actor_id, exit_reason }),
+            Err(reason) =>
+                tracing::error!("Failed to terminate ActorEntry: {}", reason.as_ref().pp()),
+        }
+    }
+}
+
+impl System {
+    /// The route to the running actor, looked up without locking its actor-entry.
+    pub(crate) fn actor_route(&self, actor_id: ActorID) -> Option<Arc<ActorRoute>> {
+        self.actor_entry_slot(actor_id)?
+            .route
+            .load_full()
+            .filter(|route| route.actor_id() == actor_id)
+    }
+
+    pub(crate) fn actor_route_add_adapter(
+        &self,
+        actor_id: ActorID,
+        type_id: TypeId,
+        adapted_tx: AdaptedMailboxTx,
+    ) {
+        if let Some(slot) = self.actor_entry_slot(actor_id) {
+            slot.route.rcu(|current| match current {
+                Some(route) if route.actor_id() == actor_id =>
+                    Some(Arc::new(route.with_adapter(type_id, adapted_tx.to_owned()))),
+                other => other.to_owned(),
+            });
+        }
+    }
+
+    fn actor_route_remove(&self, actor_id: ActorID) {
+        if let Some(slot) = self.actor_entry_slot(actor_id) {
+            slot.route.rcu(|current| {
+                current.as_ref().filter(|route| route.actor_id() != actor_id).cloned()
+            });
         }
     }
 }
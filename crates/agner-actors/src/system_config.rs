@@ -1,14 +1,24 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::dead_letter::{DeadLetterHandler, NoopDeadLetterHandler};
 use crate::exit_handler::{ExitHandler, NoopExitHandler};
+use crate::spawner::{AmbientSpawner, Spawner};
 
 /// Configuration for [`System`](crate::system::System)
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemConfig {
-    /// max number of actors in the [`System`](crate::system::System)
-    pub max_actors: usize,
+    /// expected max number of actors running in the [`System`](crate::system::System) at once
+    /// (no limit if not specified)
+    ///
+    /// This is a soft limit: the table of the actors grows on demand, and the actors spawned
+    /// beyond the limit still run. Each time the number of the actors exceeds it, a warning is
+    /// logged and
+    /// [`SystemEvent::MaxActorsExceeded`](crate::system::SystemEvent::MaxActorsExceeded)
+    /// is published.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_actors: Option<usize>,
 
     /// max duration given for an actor to gracefully terminate
     pub actor_termination_timeout: Duration,
@@ -16,25 +26,94 @@ pub struct SystemConfig {
     /// exit handler
     #[cfg_attr(feature = "serde", serde(skip, default = "defaults::default_exit_handler"))]
     pub exit_handler: Arc<dyn ExitHandler>,
+
+    /// the handler of the messages that could not be delivered
+    #[cfg_attr(feature = "serde", serde(skip, default = "defaults::default_dead_letter_handler"))]
+    pub dead_letter_handler: Arc<dyn DeadLetterHandler>,
+
+    /// the spawner running the actors (the ambient tokio runtime by default)
+    #[cfg_attr(feature = "serde", serde(skip, default = "defaults::default_spawner"))]
+    pub spawner: Arc<dyn Spawner>,
+
+    /// max number of [events](crate::system::SystemEvent) buffered for each subscriber
+    #[cfg_attr(feature = "serde", serde(default = "defaults::default_events_buffer_size"))]
+    pub events_buffer_size: usize,
+
+    /// watchdog reporting the actors whose polls take too long (disabled by default)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slow_poll_watchdog: Option<SlowPollWatchdog>,
+
+    /// record the locations of the panics caught in the actors (disabled by default)
+    ///
+    /// Unless enabled, the [`Exit::Panic`](crate::exit::Exit::Panic) reasons carry no location.
+    ///
+    /// The locations are recorded by a panic-hook, which is process-wide: once installed by a
+    /// [`System`](crate::system::System), it stays for the lifetime of the process (the previously
+    /// installed hook is still invoked).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub panic_locations: bool,
+}
+
+/// Configuration of the watchdog measuring each poll of the actors' behaviours and their jobs.
+///
+/// A poll taking longer than `threshold` is reported as a warning. If `kill_after` is set, an
+/// actor whose polls exceed the threshold that many times is terminated with
+/// [`BackendFailure::SlowPoll`](crate::exit_reason::BackendFailure::SlowPoll).
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlowPollWatchdog {
+    /// the longest poll that is not reported
+    pub threshold: Duration,
+
+    /// the number of slow polls after which the actor is terminated
+    pub kill_after: Option<usize>,
 }
 
 impl Default for SystemConfig {
     fn default() -> Self {
         Self {
-            max_actors: defaults::DEFAULT_MAX_ACTORS,
+            max_actors: None,
             actor_termination_timeout: defaults::DEFAULT_ACTOR_TERMINATION_TIMEOUT,
             exit_handler: defaults::default_exit_handler(),
+            dead_letter_handler: defaults::default_dead_letter_handler(),
+            spawner: defaults::default_spawner(),
+            events_buffer_size: defaults::DEFAULT_EVENTS_BUFFER_SIZE,
+            slow_poll_watchdog: None,
+            panic_locations: false,
         }
     }
 }
 
+impl SlowPollWatchdog {
+    pub fn new(threshold: Duration) -> Self {
+        Self { threshold, kill_after: None }
+    }
+
+    pub fn with_kill_after(self, kill_after: usize) -> Self {
+        Self { kill_after: Some(kill_after), ..self }
+    }
+}
+
 mod defaults {
     use super::*;
 
-    pub(super) const DEFAULT_MAX_ACTORS: usize = 1_024;
     pub(super) const DEFAULT_ACTOR_TERMINATION_TIMEOUT: Duration = Duration::from_secs(30);
+    pub(super) const DEFAULT_EVENTS_BUFFER_SIZE: usize = 1_024;
+
+    #[cfg(feature = "serde")]
+    pub(super) fn default_events_buffer_size() -> usize {
+        DEFAULT_EVENTS_BUFFER_SIZE
+    }
 
     pub(super) fn default_exit_handler() -> Arc<dyn ExitHandler> {
         Arc::new(NoopExitHandler)
     }
+
+    pub(super) fn default_dead_letter_handler() -> Arc<dyn DeadLetterHandler> {
+        Arc::new(NoopDeadLetterHandler)
+    }
+
+    pub(super) fn default_spawner() -> Arc<dyn Spawner> {
+        Arc::new(AmbientSpawner)
+    }
 }
@@ -0,0 +1,24 @@
+use std::fmt;
+
+/// A reference to a timer, started via
+/// [`Context::send_after`](crate::context::Context::send_after).
+///
+/// Can be used to cancel the timer via
+/// [`Context::cancel_timer`](crate::context::Context::cancel_timer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerRef(usize);
+
+impl TimerRef {
+    /// Create a new [`TimerRef`], unique within the [`System`](crate::system::System) it is created
+    /// by (see `RefSeqs::new_timer_ref`).
+    pub(crate) fn new(seq: usize) -> Self {
+        Self(seq)
+    }
+}
+
+impl fmt::Display for TimerRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#Timer<{}>", self.0)
+    }
+}
@@ -1,22 +1,23 @@
 use std::convert::Infallible;
 use std::time::Duration;
 
-use agner_actors::{Context, System, SystemConfig};
+use agner_actors::{Context, System, SystemConfig, SystemEvent};
+use futures::StreamExt;
 
 mod common;
 
 #[test]
-fn hit_small_system_limit() {
-    common::run(hit_system_limit(5));
+fn exceed_small_system_limit() {
+    common::run(exceed_system_limit(5));
 }
 
 #[test]
 #[ignore]
-fn hit_large_system_limit() {
-    common::run(hit_system_limit(1_000_000));
+fn exceed_large_system_limit() {
+    common::run(exceed_system_limit(1_000_000));
 }
 
-async fn hit_system_limit(max_actors: usize) {
+async fn exceed_system_limit(max_actors: usize) {
     async fn actor_behaviour(context: &mut Context<Infallible>, _arg: usize) {
         loop {
             let event = context.next_event().await;
@@ -24,13 +25,50 @@ async fn hit_system_limit(max_actors: usize) {
         }
     }
 
-    let system = System::new(SystemConfig { max_actors, ..Default::default() });
+    let system = System::new(SystemConfig { max_actors: Some(max_actors), ..Default::default() });
+    let mut events = Box::pin(system.subscribe_events());
 
     for i in 0..max_actors {
         assert!(system.spawn(actor_behaviour, i, Default::default()).await.is_ok());
     }
 
-    assert!(system.spawn(actor_behaviour, max_actors, Default::default()).await.is_err());
+    let beyond_limit = system.spawn(actor_behaviour, max_actors, Default::default()).await.unwrap();
+    let exceeded = loop {
+        match events.next().await.expect("events stream ended") {
+            SystemEvent::MaxActorsExceeded { actor_id, max_actors } => break (actor_id, max_actors),
+            _ => continue,
+        }
+    };
+    assert_eq!(exceeded, (beyond_limit, max_actors));
+    assert!(system.actor_info(beyond_limit).await.is_some());
 
     tokio::time::sleep(Duration::from_secs(1)).await;
 }
+
+#[test]
+fn unlimited_system_grows() {
+    common::run(async {
+        async fn short_lived(_context: &mut Context<Infallible>, _arg: ()) {}
+
+        let system = System::new(Default::default());
+
+        let mut actors = vec![];
+        for _ in 0..2_000 {
+            actors.push(system.spawn(actor_behaviour, (), Default::default()).await.unwrap());
+        }
+        assert_eq!(system.all_actors().count().await, actors.len());
+
+        let stale = system.spawn(short_lived, (), Default::default()).await.unwrap();
+        system.wait(stale).await;
+        let fresh = system.spawn(actor_behaviour, (), Default::default()).await.unwrap();
+        assert_ne!(fresh, stale);
+        assert!(system.actor_info(stale).await.is_none());
+        assert!(system.actor_info(fresh).await.is_some());
+    })
+}
+
+async fn actor_behaviour(context: &mut Context<Infallible>, _arg: ()) {
+    loop {
+        context.next_event().await;
+    }
+}
@@ -6,12 +6,14 @@ use tokio::sync::{oneshot, Mutex};
 
 mod common;
 
+type Output = Arc<Mutex<Vec<(&'static str, &'static str)>>>;
+
 #[test]
 fn send_single_compatible_message() {
     let output = Arc::new(Mutex::new(Vec::<(&'static str, &'static str)>::new()));
     async fn actor_behaviour(
         context: &mut Context<&'static str>,
-        (actor_name, output): (&'static str, Arc<Mutex<Vec<(&'static str, &'static str)>>>),
+        (actor_name, output): (&'static str, Output),
     ) {
         loop {
             if let Event::Message(message) = context.next_event().await {
@@ -134,7 +136,7 @@ async fn actor_ring(ring_size: usize) {
         }
     }
 
-    let system = System::new(SystemConfig { max_actors: ring_size, ..Default::default() });
+    let system = System::new(SystemConfig { max_actors: Some(ring_size), ..Default::default() });
 
     let mut prev = None;
 
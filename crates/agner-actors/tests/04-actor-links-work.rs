@@ -40,6 +40,7 @@ fn links_test() {
                 Event::Signal(Signal::Exit(terminated, reason)) => {
                     tracing::info!("[{}] {} has exited: {}", context.actor_id(), terminated, reason)
                 },
+                Event::Signal(Signal::Down(..)) => unreachable!("no monitors installed"),
             }
         }
     }
@@ -0,0 +1,177 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::{ActorID, Context, Event, Exit, MonitorRef, Signal, System};
+use tokio::sync::{mpsc, oneshot};
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+enum Request {
+    Monitor(ActorID, oneshot::Sender<MonitorRef>),
+    Demonitor(MonitorRef, oneshot::Sender<()>),
+}
+
+async fn watcher(
+    context: &mut Context<Request>,
+    downs: mpsc::UnboundedSender<(MonitorRef, ActorID, Exit)>,
+) {
+    loop {
+        match context.next_event().await {
+            Event::Message(Request::Monitor(actor_id, reply_to)) => {
+                let _ = reply_to.send(context.monitor(actor_id).await);
+            },
+            Event::Message(Request::Demonitor(monitor_ref, reply_to)) => {
+                context.demonitor(monitor_ref).await;
+                let _ = reply_to.send(());
+            },
+            Event::Signal(Signal::Down(monitor_ref, actor_id, exit_reason)) => {
+                let _ = downs.send((monitor_ref, actor_id, exit_reason));
+            },
+            Event::Signal(Signal::Exit(..)) => unreachable!("does not trap exits"),
+        }
+    }
+}
+
+async fn target(_context: &mut Context<Infallible>, _arg: ()) {
+    std::future::pending().await
+}
+
+async fn monitor(system: &System, watcher: ActorID, actor_id: ActorID) -> MonitorRef {
+    let (tx, rx) = oneshot::channel();
+    system.send(watcher, Request::Monitor(actor_id, tx)).await;
+    rx.await.expect("watcher is gone")
+}
+
+async fn demonitor(system: &System, watcher: ActorID, monitor_ref: MonitorRef) {
+    let (tx, rx) = oneshot::channel();
+    system.send(watcher, Request::Demonitor(monitor_ref, tx)).await;
+    rx.await.expect("watcher is gone")
+}
+
+#[test]
+fn down_is_delivered_upon_normal_exit() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (downs_tx, mut downs_rx) = mpsc::unbounded_channel();
+
+        let w = system.spawn(watcher, downs_tx, Default::default()).await.unwrap();
+        let t = system.spawn(target, (), Default::default()).await.unwrap();
+
+        let monitor_ref = monitor(&system, w, t).await;
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        let t_info = system.actor_info(t).await.unwrap();
+        assert_eq!(&t_info.monitored_by[..], &[(monitor_ref, w)]);
+        let w_info = system.actor_info(w).await.unwrap();
+        assert_eq!(&w_info.monitors[..], &[(monitor_ref, t)]);
+
+        system.exit(t, Exit::normal()).await;
+
+        let (down_ref, down_actor, down_reason) = downs_rx.recv().await.unwrap();
+        assert_eq!(down_ref, monitor_ref);
+        assert_eq!(down_actor, t);
+        assert!(down_reason.is_normal());
+
+        let w_info = system.actor_info(w).await.unwrap();
+        assert!(w_info.monitors.is_empty());
+    })
+}
+
+#[test]
+fn down_no_actor_if_monitored_actor_is_gone() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (downs_tx, mut downs_rx) = mpsc::unbounded_channel();
+
+        let w = system.spawn(watcher, downs_tx, Default::default()).await.unwrap();
+        let t = system.spawn(target, (), Default::default()).await.unwrap();
+
+        system.exit(t, Exit::shutdown()).await;
+        assert!(system.wait(t).await.is_shutdown());
+
+        let monitor_ref = monitor(&system, w, t).await;
+
+        let (down_ref, down_actor, down_reason) = downs_rx.recv().await.unwrap();
+        assert_eq!(down_ref, monitor_ref);
+        assert_eq!(down_actor, t);
+        assert!(down_reason.is_no_actor());
+    })
+}
+
+#[test]
+fn no_down_after_demonitor() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (downs_tx, mut downs_rx) = mpsc::unbounded_channel();
+
+        let w = system.spawn(watcher, downs_tx, Default::default()).await.unwrap();
+        let t1 = system.spawn(target, (), Default::default()).await.unwrap();
+        let t2 = system.spawn(target, (), Default::default()).await.unwrap();
+
+        let ref_1 = monitor(&system, w, t1).await;
+        let ref_2 = monitor(&system, w, t2).await;
+        demonitor(&system, w, ref_1).await;
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        assert!(system.actor_info(t1).await.unwrap().monitored_by.is_empty());
+
+        system.exit(t1, Exit::kill()).await;
+        system.exit(t2, Exit::kill()).await;
+
+        let (down_ref, down_actor, down_reason) = downs_rx.recv().await.unwrap();
+        assert_eq!(down_ref, ref_2);
+        assert_eq!(down_actor, t2);
+        assert!(down_reason.is_kill());
+
+        tokio::time::sleep(SMALL_DELAY).await;
+        assert!(downs_rx.try_recv().is_err());
+
+        assert!(system.actor_info(w).await.is_some());
+    })
+}
+
+#[test]
+fn monitors_are_removed_when_watcher_exits() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (downs_tx, _downs_rx) = mpsc::unbounded_channel();
+
+        let w = system.spawn(watcher, downs_tx, Default::default()).await.unwrap();
+        let t = system.spawn(target, (), Default::default()).await.unwrap();
+
+        let monitor_ref = monitor(&system, w, t).await;
+        tokio::time::sleep(SMALL_DELAY).await;
+        assert_eq!(&system.actor_info(t).await.unwrap().monitored_by[..], &[(monitor_ref, w)]);
+
+        system.exit(w, Exit::shutdown()).await;
+        assert!(system.wait(w).await.is_shutdown());
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        assert!(system.actor_info(t).await.unwrap().monitored_by.is_empty());
+    })
+}
+
+#[test]
+fn monitor_after_the_system_is_gone() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (downs_tx, mut downs_rx) = mpsc::unbounded_channel();
+
+        let w = system.spawn(watcher, downs_tx, Default::default()).await.unwrap();
+        let t = system.spawn(target, (), Default::default()).await.unwrap();
+        let w_ref = system.actor_ref::<Request>(w).await.unwrap();
+        std::mem::drop(system);
+
+        let (tx, rx) = oneshot::channel();
+        w_ref.send(Request::Monitor(t, tx)).await.unwrap();
+        let monitor_ref = rx.await.expect("watcher is gone");
+
+        let (down_ref, down_actor, down_reason) = downs_rx.recv().await.unwrap();
+        assert_eq!(down_ref, monitor_ref);
+        assert_eq!(down_actor, t);
+        assert!(down_reason.is_no_actor());
+    })
+}
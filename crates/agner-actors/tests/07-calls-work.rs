@@ -0,0 +1,149 @@
+use std::time::{Duration, Instant};
+
+use agner_actors::system_error::CallError;
+use agner_actors::{Context, Exit, ReplyTo, System};
+
+mod common;
+
+const CALL_TIMEOUT: Duration = Duration::from_secs(1);
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+enum Request {
+    Double(usize, ReplyTo<usize>),
+    Ignore(ReplyTo<usize>),
+    Drop(ReplyTo<usize>),
+    Crash(ReplyTo<usize>),
+}
+
+async fn server(context: &mut Context<Request>, _arg: ()) {
+    let mut ignored = vec![];
+    loop {
+        match context.next_message().await {
+            Request::Double(n, reply_to) => {
+                let _ = reply_to.reply(n * 2);
+            },
+            Request::Ignore(reply_to) => ignored.push(reply_to),
+            Request::Drop(reply_to) => std::mem::drop(reply_to),
+            Request::Crash(_reply_to) => {
+                context.exit(Exit::custom(std::io::Error::other("crashed"))).await;
+                unreachable!()
+            },
+        }
+    }
+}
+
+#[test]
+fn call_returns_reply() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let s = system.spawn(server, (), Default::default()).await.unwrap();
+
+        for n in 0..10 {
+            let reply = system
+                .call(s, |reply_to| Request::Double(n, reply_to), CALL_TIMEOUT)
+                .await
+                .unwrap();
+            assert_eq!(reply, n * 2);
+        }
+    })
+}
+
+#[test]
+fn call_to_a_non_existent_actor() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let s = system.spawn(server, (), Default::default()).await.unwrap();
+        system.exit(s, Exit::shutdown()).await;
+        assert!(system.wait(s).await.is_shutdown());
+
+        let err = system
+            .call(s, |reply_to| Request::Double(1, reply_to), CALL_TIMEOUT)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CallError::NoActor));
+    })
+}
+
+#[test]
+fn call_with_invalid_message_type() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let s = system.spawn(server, (), Default::default()).await.unwrap();
+
+        let err = system
+            .call(s, |reply_to: ReplyTo<usize>| (1usize, reply_to), CALL_TIMEOUT)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CallError::InvalidMessageType));
+    })
+}
+
+#[test]
+fn call_times_out() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let s = system.spawn(server, (), Default::default()).await.unwrap();
+
+        let err = system.call(s, Request::Ignore, SMALL_DELAY).await.unwrap_err();
+        assert!(matches!(err, CallError::Timeout));
+
+        // the abandoned call should not affect the subsequent ones
+        let reply = system
+            .call(s, |reply_to| Request::Double(2, reply_to), CALL_TIMEOUT)
+            .await
+            .unwrap();
+        assert_eq!(reply, 4);
+    })
+}
+
+#[test]
+fn call_fails_when_reply_to_is_dropped() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let s = system.spawn(server, (), Default::default()).await.unwrap();
+
+        let started_at = Instant::now();
+        let err = system.call(s, Request::Drop, CALL_TIMEOUT).await.unwrap_err();
+        assert!(matches!(err, CallError::NoReply), "unexpected: {:?}", err);
+        assert!(started_at.elapsed() < CALL_TIMEOUT);
+
+        let reply = system
+            .call(s, |reply_to| Request::Double(3, reply_to), CALL_TIMEOUT)
+            .await
+            .unwrap();
+        assert_eq!(reply, 6);
+    })
+}
+
+#[test]
+fn call_fails_when_callee_exits() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let s = system.spawn(server, (), Default::default()).await.unwrap();
+
+        let err = system.call(s, Request::Crash, CALL_TIMEOUT).await.unwrap_err();
+        let CallError::CalleeExited(exit_reason) = err else { panic!("unexpected: {:?}", err) };
+        assert!(exit_reason.is_custom());
+    })
+}
+
+#[test]
+fn call_fails_when_callee_is_killed_while_handling() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let s = system.spawn(server, (), Default::default()).await.unwrap();
+
+        let killer = {
+            let system = system.to_owned();
+            async move {
+                tokio::time::sleep(SMALL_DELAY).await;
+                system.exit(s, Exit::kill()).await;
+            }
+        };
+        let (result, ()) =
+            tokio::join!(system.call(s, Request::Ignore, Duration::from_secs(10)), killer);
+        let CallError::CalleeExited(exit_reason) = result.unwrap_err() else { panic!() };
+        assert!(exit_reason.is_kill());
+    })
+}
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use agner_actors::{Context, Exit, SpawnOpts, System};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Noise(usize),
+    Reply(usize),
+}
+
+async fn waiting_for_reply(
+    context: &mut Context<Message>,
+    (deadline_opt, report_tx): (Option<Duration>, mpsc::UnboundedSender<Option<Message>>),
+) {
+    let is_reply = |m: &Message| matches!(m, Message::Reply(_));
+    let reply = if let Some(timeout) = deadline_opt {
+        context.receive_matching_until(is_reply, Instant::now() + timeout).await
+    } else {
+        Some(context.receive_matching(is_reply).await)
+    };
+    let _ = report_tx.send(reply);
+
+    loop {
+        let message = context.next_message().await;
+        let _ = report_tx.send(Some(message));
+    }
+}
+
+#[test]
+fn non_matching_messages_are_replayed_in_order() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (report_tx, mut report_rx) = mpsc::unbounded_channel();
+
+        let actor = system
+            .spawn(waiting_for_reply, (None, report_tx), Default::default())
+            .await
+            .unwrap();
+
+        for i in 0..3 {
+            system.send(actor, Message::Noise(i)).await;
+        }
+        tokio::time::sleep(SMALL_DELAY).await;
+        assert!(report_rx.try_recv().is_err());
+
+        system.send(actor, Message::Noise(3)).await;
+        system.send(actor, Message::Reply(42)).await;
+        system.send(actor, Message::Noise(4)).await;
+
+        assert_eq!(report_rx.recv().await.unwrap(), Some(Message::Reply(42)));
+        for i in 0..5 {
+            assert_eq!(report_rx.recv().await.unwrap(), Some(Message::Noise(i)));
+        }
+    })
+}
+
+#[test]
+fn receive_matching_until_gives_up_at_deadline() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (report_tx, mut report_rx) = mpsc::unbounded_channel();
+
+        let actor = system
+            .spawn(waiting_for_reply, (Some(SMALL_DELAY), report_tx), Default::default())
+            .await
+            .unwrap();
+        system.send(actor, Message::Noise(0)).await;
+
+        assert_eq!(report_rx.recv().await.unwrap(), None);
+        assert_eq!(report_rx.recv().await.unwrap(), Some(Message::Noise(0)));
+
+        system.send(actor, Message::Reply(1)).await;
+        assert_eq!(report_rx.recv().await.unwrap(), Some(Message::Reply(1)));
+    })
+}
+
+#[test]
+fn saved_messages_count_towards_inbox_size() {
+    common::run(async {
+        const INBOX_SIZE: usize = 3;
+
+        let system = System::new(Default::default());
+        let (report_tx, _report_rx) = mpsc::unbounded_channel();
+
+        let actor = system
+            .spawn(
+                waiting_for_reply,
+                (None, report_tx),
+                SpawnOpts::new().with_msg_inbox_size(INBOX_SIZE),
+            )
+            .await
+            .unwrap();
+
+        for i in 0..INBOX_SIZE {
+            system.send(actor, Message::Noise(i)).await;
+        }
+        tokio::time::sleep(SMALL_DELAY).await;
+        assert_eq!(system.actor_info(actor).await.unwrap().m_queue_len, (INBOX_SIZE, INBOX_SIZE));
+
+        system.send(actor, Message::Noise(INBOX_SIZE)).await;
+        let exit_reason = system.wait(actor).await;
+        assert!(matches!(exit_reason, Exit::Backend(_)), "{:?}", exit_reason);
+    })
+}
@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use agner_actors::{Context, Exit, System, TimerRef};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+const LARGE_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+enum Request {
+    SendAfter(Duration, &'static str, oneshot::Sender<TimerRef>),
+    StartTimer(Duration, oneshot::Sender<TimerRef>),
+    Cancel(TimerRef, oneshot::Sender<Option<Duration>>),
+    Fired(&'static str),
+    TimerFired(TimerRef),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Fired {
+    Named(&'static str),
+    Ref(TimerRef),
+}
+
+async fn timers_user(context: &mut Context<Request>, fired_tx: mpsc::UnboundedSender<Fired>) {
+    loop {
+        match context.next_message().await {
+            Request::SendAfter(delay, name, reply_to) => {
+                let _ = reply_to.send(context.send_after(delay, Request::Fired(name)).await);
+            },
+            Request::StartTimer(delay, reply_to) => {
+                let _ = reply_to.send(context.start_timer(delay, Request::TimerFired).await);
+            },
+            Request::Cancel(timer_ref, reply_to) => {
+                let _ = reply_to.send(context.cancel_timer(timer_ref).await);
+            },
+            Request::Fired(name) => {
+                let _ = fired_tx.send(Fired::Named(name));
+            },
+            Request::TimerFired(timer_ref) => {
+                let _ = fired_tx.send(Fired::Ref(timer_ref));
+            },
+        }
+    }
+}
+
+async fn send_after(
+    system: &System,
+    actor: agner_actors::ActorID,
+    delay: Duration,
+    name: &'static str,
+) -> TimerRef {
+    let (tx, rx) = oneshot::channel();
+    system.send(actor, Request::SendAfter(delay, name, tx)).await;
+    rx.await.unwrap()
+}
+
+#[test]
+fn timers_fire_in_order_of_deadlines() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (fired_tx, mut fired_rx) = mpsc::unbounded_channel();
+        let actor = system.spawn(timers_user, fired_tx, Default::default()).await.unwrap();
+
+        let started_at = Instant::now();
+        send_after(&system, actor, SMALL_DELAY * 3, "three").await;
+        send_after(&system, actor, SMALL_DELAY, "one-a").await;
+        send_after(&system, actor, SMALL_DELAY, "one-b").await;
+        send_after(&system, actor, SMALL_DELAY * 2, "two").await;
+
+        let (tx, rx) = oneshot::channel();
+        system.send(actor, Request::StartTimer(SMALL_DELAY * 4, tx)).await;
+        let timer_ref = rx.await.unwrap();
+
+        for name in ["one-a", "one-b", "two", "three"] {
+            assert_eq!(fired_rx.recv().await.unwrap(), Fired::Named(name));
+        }
+        assert_eq!(fired_rx.recv().await.unwrap(), Fired::Ref(timer_ref));
+        assert!(started_at.elapsed() >= SMALL_DELAY * 4);
+
+        assert_eq!(system.actor_info(actor).await.unwrap().timers_count, 0);
+    })
+}
+
+#[test]
+fn cancelled_timers_do_not_fire() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (fired_tx, mut fired_rx) = mpsc::unbounded_channel();
+        let actor = system.spawn(timers_user, fired_tx, Default::default()).await.unwrap();
+
+        let cancelled = send_after(&system, actor, SMALL_DELAY, "cancelled").await;
+        send_after(&system, actor, SMALL_DELAY * 2, "fired").await;
+
+        let (tx, rx) = oneshot::channel();
+        system.send(actor, Request::Cancel(cancelled, tx)).await;
+        let remaining = rx.await.unwrap().expect("timer should be active");
+        assert!(remaining <= SMALL_DELAY);
+        assert_eq!(system.actor_info(actor).await.unwrap().timers_count, 1);
+
+        assert_eq!(fired_rx.recv().await.unwrap(), Fired::Named("fired"));
+
+        let (tx, rx) = oneshot::channel();
+        system.send(actor, Request::Cancel(cancelled, tx)).await;
+        assert!(rx.await.unwrap().is_none());
+    })
+}
+
+#[test]
+fn timers_are_cancelled_upon_exit() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (fired_tx, mut fired_rx) = mpsc::unbounded_channel();
+        let actor = system.spawn(timers_user, fired_tx, Default::default()).await.unwrap();
+
+        send_after(&system, actor, LARGE_DELAY, "never").await;
+        system.send_after(actor, LARGE_DELAY, Request::Fired("never either")).await;
+
+        system.exit(actor, Exit::shutdown()).await;
+        assert!(system.wait(actor).await.is_shutdown());
+        assert!(fired_rx.recv().await.is_none());
+    })
+}
+
+#[test]
+fn system_send_after() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (fired_tx, mut fired_rx) = mpsc::unbounded_channel();
+        let actor = system.spawn(timers_user, fired_tx, Default::default()).await.unwrap();
+
+        let started_at = Instant::now();
+        system.send_after(actor, SMALL_DELAY, Request::Fired("delayed")).await;
+        system.send(actor, Request::Fired("immediate")).await;
+
+        assert_eq!(fired_rx.recv().await.unwrap(), Fired::Named("immediate"));
+        assert_eq!(fired_rx.recv().await.unwrap(), Fired::Named("delayed"));
+        assert!(started_at.elapsed() >= SMALL_DELAY);
+    })
+}
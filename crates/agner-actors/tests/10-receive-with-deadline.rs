@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use agner_actors::{Context, Event, Exit, Received, System};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+enum Request {
+    NextEvent(Duration, oneshot::Sender<Received<Event<Request>>>),
+    NextMessage(Duration, oneshot::Sender<Received<Request>>),
+    AbandonWait(Duration, oneshot::Sender<()>),
+    Ping(usize),
+}
+
+async fn receiver(context: &mut Context<Request>, mut requests: mpsc::UnboundedReceiver<Request>) {
+    context.trap_exit(true).await;
+
+    while let Some(request) = requests.recv().await {
+        match request {
+            Request::NextEvent(timeout, reply_to) => {
+                let _ = reply_to.send(context.next_event_timeout(timeout).await);
+            },
+            Request::NextMessage(timeout, reply_to) => {
+                let deadline = Instant::now() + timeout;
+                let _ = reply_to.send(context.next_message_until(deadline).await);
+            },
+            Request::AbandonWait(timeout, reply_to) => {
+                let _ = tokio::time::timeout(timeout, context.next_event()).await;
+                let _ = reply_to.send(());
+            },
+            Request::Ping(_) => unreachable!("pings are sent via the system"),
+        }
+    }
+}
+
+struct Receiver {
+    system: System,
+    actor_id: agner_actors::ActorID,
+    requests_tx: mpsc::UnboundedSender<Request>,
+}
+
+impl Receiver {
+    async fn start() -> Self {
+        let system = System::new(Default::default());
+        let (requests_tx, requests_rx) = mpsc::unbounded_channel();
+        let actor_id = system.spawn(receiver, requests_rx, Default::default()).await.unwrap();
+        Self { system, actor_id, requests_tx }
+    }
+
+    async fn next_event(&self, timeout: Duration) -> Received<Event<Request>> {
+        let (tx, rx) = oneshot::channel();
+        self.requests_tx.send(Request::NextEvent(timeout, tx)).unwrap();
+        rx.await.unwrap()
+    }
+
+    async fn next_message(&self, timeout: Duration) -> Received<Request> {
+        let (tx, rx) = oneshot::channel();
+        self.requests_tx.send(Request::NextMessage(timeout, tx)).unwrap();
+        rx.await.unwrap()
+    }
+
+    async fn abandon_wait(&self, timeout: Duration) {
+        let (tx, rx) = oneshot::channel();
+        self.requests_tx.send(Request::AbandonWait(timeout, tx)).unwrap();
+        rx.await.unwrap()
+    }
+}
+
+#[test]
+fn zero_timeout_distinguishes_empty_inbox() {
+    common::run(async {
+        let r = Receiver::start().await;
+
+        assert!(r.next_event(Duration::ZERO).await.is_timeout());
+        assert!(r.next_message(Duration::ZERO).await.is_timeout());
+
+        r.system.send(r.actor_id, Request::Ping(1)).await;
+        r.system.send(r.actor_id, Request::Ping(2)).await;
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        assert!(matches!(
+            r.next_event(Duration::ZERO).await,
+            Received::Event(Event::Message(Request::Ping(1)))
+        ));
+        assert!(matches!(r.next_message(Duration::ZERO).await, Received::Event(Request::Ping(2))));
+        assert!(r.next_event(Duration::ZERO).await.is_timeout());
+    })
+}
+
+#[test]
+fn events_arriving_before_deadline_are_received() {
+    common::run(async {
+        let r = Receiver::start().await;
+
+        let started_at = Instant::now();
+        let (received, ()) = tokio::join!(r.next_message(SMALL_DELAY * 10), async {
+            tokio::time::sleep(SMALL_DELAY).await;
+            r.system.send(r.actor_id, Request::Ping(1)).await;
+        });
+        assert!(matches!(received, Received::Event(Request::Ping(1))));
+        assert!(started_at.elapsed() < SMALL_DELAY * 10);
+
+        let (received, ()) = tokio::join!(r.next_event(SMALL_DELAY * 10), async {
+            tokio::time::sleep(SMALL_DELAY).await;
+            r.system.exit(r.actor_id, Exit::shutdown()).await;
+        });
+        assert!(matches!(received, Received::Event(Event::Signal(_))));
+
+        let started_at = Instant::now();
+        assert!(r.next_message(SMALL_DELAY).await.is_timeout());
+        assert!(started_at.elapsed() >= SMALL_DELAY);
+    })
+}
+
+#[test]
+fn no_event_is_lost_on_cancellation() {
+    common::run(async {
+        let r = Receiver::start().await;
+
+        for _ in 0..10 {
+            r.abandon_wait(Duration::from_millis(1)).await;
+        }
+        r.system.send(r.actor_id, Request::Ping(1)).await;
+        assert!(matches!(
+            r.next_message(SMALL_DELAY * 10).await,
+            Received::Event(Request::Ping(1))
+        ));
+    })
+}
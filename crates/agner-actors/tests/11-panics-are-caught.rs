@@ -0,0 +1,109 @@
+use std::convert::Infallible;
+
+use agner_actors::{Context, Event, Exit, Signal, System, SystemConfig};
+use tokio::sync::{mpsc, oneshot};
+
+mod common;
+
+async fn panicking(context: &mut Context<&'static str>, _args: ()) -> Infallible {
+    let message = context.next_message().await;
+    panic!("{}", message)
+}
+
+async fn panicking_job(context: &mut Context<Infallible>, _args: ()) -> Infallible {
+    context.spawn_job(async { panic!("job failed") }).await;
+    std::future::pending().await
+}
+
+async fn resuming_unwind(_context: &mut Context<Infallible>, _args: ()) -> Infallible {
+    let _ = std::panic::catch_unwind(|| panic!("caught"));
+    tokio::task::yield_now().await;
+    std::panic::resume_unwind(Box::new("resumed"))
+}
+
+async fn watcher(
+    context: &mut Context<Infallible>,
+    (watched, signals_tx): (agner_actors::ActorID, mpsc::UnboundedSender<Signal>),
+) -> Infallible {
+    context.trap_exit(true).await;
+    context.link(watched).await;
+    context.monitor(watched).await;
+    loop {
+        let Event::Signal(signal) = context.next_event().await;
+        let _ = signals_tx.send(signal);
+    }
+}
+
+fn assert_panic(exit: &Exit, expected_message: &str) {
+    let Exit::Panic { message, location } = exit else { panic!("not a panic: {:?}", exit) };
+    assert_eq!(message.as_ref(), expected_message);
+    let location = location.as_deref().expect("no location");
+    assert!(location.contains("11-panics-are-caught.rs"), "{}", location);
+}
+
+#[test]
+fn panic_in_behaviour_is_an_exit_reason() {
+    common::run(async {
+        let system = System::new(SystemConfig { panic_locations: true, ..Default::default() });
+        let actor = system.spawn(panicking, (), Default::default()).await.unwrap();
+
+        let (signals_tx, mut signals_rx) = mpsc::unbounded_channel();
+        let watcher = system.spawn(watcher, (actor, signals_tx), Default::default()).await.unwrap();
+        let (linked_tx, linked_rx) = oneshot::channel();
+        tokio::spawn({
+            let system = system.to_owned();
+            async move {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                system.send(actor, "oops").await;
+                let _ = linked_tx.send(());
+            }
+        });
+        linked_rx.await.unwrap();
+
+        assert_panic(&system.wait(actor).await, "oops");
+
+        let mut got_exit = false;
+        let mut got_down = false;
+        while !(got_exit && got_down) {
+            match signals_rx.recv().await.unwrap() {
+                Signal::Exit(from, reason) => {
+                    assert_eq!(from, actor);
+                    assert_panic(&reason, "oops");
+                    got_exit = true;
+                },
+                Signal::Down(_, from, reason) => {
+                    assert_eq!(from, actor);
+                    assert_panic(&reason, "oops");
+                    got_down = true;
+                },
+            }
+        }
+
+        assert!(system.actor_info(watcher).await.is_some());
+    })
+}
+
+#[test]
+fn panic_in_job_is_an_exit_reason() {
+    common::run(async {
+        let system = System::new(SystemConfig { panic_locations: true, ..Default::default() });
+        let actor = system.spawn(panicking_job, (), Default::default()).await.unwrap();
+
+        assert_panic(&system.wait(actor).await, "job failed");
+    })
+}
+
+#[test]
+fn location_of_an_earlier_poll_is_not_attributed() {
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+    runtime.block_on(async {
+        let system = System::new(SystemConfig { panic_locations: true, ..Default::default() });
+        let actor = system.spawn(resuming_unwind, (), Default::default()).await.unwrap();
+
+        let Exit::Panic { message, location } = system.wait(actor).await else {
+            panic!("not a panic")
+        };
+        assert_eq!(message.as_ref(), "resumed");
+        assert!(location.is_none(), "{:?}", location);
+    })
+}
@@ -0,0 +1,130 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::{ActorID, Context, Event, Exit, System, SystemConfig};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+enum Observed {
+    Terminated(Exit),
+    SigExit(ActorID, Exit),
+}
+
+async fn with_terminate_hook(
+    context: &mut Context<oneshot::Sender<()>>,
+    (hook_duration, observed_tx): (Duration, mpsc::UnboundedSender<Observed>),
+) -> Infallible {
+    context
+        .on_terminate(move |exit_reason| async move {
+            tokio::time::sleep(hook_duration).await;
+            let _ = observed_tx.send(Observed::Terminated(exit_reason));
+        })
+        .await;
+
+    loop {
+        let ready_tx = context.next_message().await;
+        let _ = ready_tx.send(());
+    }
+}
+
+async fn sig_exit_observer(
+    context: &mut Context<Infallible>,
+    (watched, observed_tx): (ActorID, mpsc::UnboundedSender<Observed>),
+) -> Infallible {
+    context.trap_exit(true).await;
+    context.link(watched).await;
+    loop {
+        let Event::Signal(signal) = context.next_event().await;
+        if let agner_actors::Signal::Exit(from, reason) = signal {
+            let _ = observed_tx.send(Observed::SigExit(from, reason));
+        }
+    }
+}
+
+async fn spawn_with_hook(
+    system: &System,
+    hook_duration: Duration,
+    observed_tx: mpsc::UnboundedSender<Observed>,
+) -> ActorID {
+    let actor = system
+        .spawn(with_terminate_hook, (hook_duration, observed_tx), Default::default())
+        .await
+        .unwrap();
+
+    // make sure the hook is installed
+    let (ready_tx, ready_rx) = oneshot::channel::<()>();
+    system.send(actor, ready_tx).await;
+    ready_rx.await.unwrap();
+
+    actor
+}
+
+#[test]
+fn terminate_hook_runs_before_links_are_notified() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (observed_tx, mut observed_rx) = mpsc::unbounded_channel();
+
+        let actor = spawn_with_hook(&system, SMALL_DELAY, observed_tx.to_owned()).await;
+        let _observer = system
+            .spawn(sig_exit_observer, (actor, observed_tx), Default::default())
+            .await
+            .unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        system.exit(actor, Exit::shutdown()).await;
+
+        let Observed::Terminated(reason) = observed_rx.recv().await.unwrap() else {
+            panic!("the terminate-hook should be observed first")
+        };
+        assert!(reason.is_shutdown());
+
+        let Observed::SigExit(from, reason) = observed_rx.recv().await.unwrap() else {
+            panic!("expected a sig-exit")
+        };
+        assert_eq!(from, actor);
+        assert!(reason.is_shutdown());
+
+        assert!(system.wait(actor).await.is_shutdown());
+    })
+}
+
+#[test]
+fn terminate_hook_is_bounded_by_termination_timeout() {
+    common::run(async {
+        let system = System::new(SystemConfig {
+            actor_termination_timeout: SMALL_DELAY,
+            ..Default::default()
+        });
+        let (observed_tx, mut observed_rx) = mpsc::unbounded_channel();
+
+        let actor = spawn_with_hook(&system, Duration::from_secs(60), observed_tx).await;
+
+        let started_at = Instant::now();
+        system.exit(actor, Exit::shutdown()).await;
+        assert!(system.wait(actor).await.is_shutdown());
+
+        assert!(started_at.elapsed() < SMALL_DELAY * 5);
+        assert!(observed_rx.recv().await.is_none());
+    })
+}
+
+#[test]
+fn terminate_hook_is_skipped_on_kill() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (observed_tx, mut observed_rx) = mpsc::unbounded_channel();
+
+        let actor = spawn_with_hook(&system, Duration::ZERO, observed_tx).await;
+
+        system.exit(actor, Exit::kill()).await;
+        assert!(system.wait(actor).await.is_kill());
+
+        assert!(observed_rx.recv().await.is_none());
+    })
+}
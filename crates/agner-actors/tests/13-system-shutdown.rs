@@ -0,0 +1,163 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::system_error::SysSpawnError;
+use agner_actors::{ActorID, Context, Event, Exit, ParentActor, Signal, System, SystemConfig};
+use futures::StreamExt;
+use tokio::sync::mpsc;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+async fn child(context: &mut Context<Infallible>, terminated_tx: mpsc::UnboundedSender<ActorID>) {
+    let actor_id = context.actor_id();
+    context
+        .on_terminate(move |_| async move {
+            let _ = terminated_tx.send(actor_id);
+        })
+        .await;
+    std::future::pending().await
+}
+
+async fn parent(
+    context: &mut Context<Infallible>,
+    terminated_tx: mpsc::UnboundedSender<ActorID>,
+) -> Exit {
+    context.trap_exit(true).await;
+
+    let system = context.system();
+    let mut children = vec![];
+    for _ in 0..3 {
+        let child_id =
+            system.spawn(child, terminated_tx.to_owned(), Default::default()).await.unwrap();
+        system.put_data(child_id, ParentActor(context.actor_id())).await;
+        context.link(child_id).await;
+        children.push(child_id);
+    }
+
+    loop {
+        let Event::Signal(Signal::Exit(from, reason)) = context.next_event().await else {
+            continue
+        };
+        if from != context.actor_id() {
+            continue
+        }
+        for child_id in children.into_iter().rev() {
+            system.exit(child_id, reason.to_owned()).await;
+            system.wait(child_id).await;
+        }
+        let _ = terminated_tx.send(context.actor_id());
+        break reason
+    }
+}
+
+async fn stubborn(context: &mut Context<Infallible>, _args: ()) -> Infallible {
+    context.trap_exit(true).await;
+    loop {
+        context.next_event().await;
+    }
+}
+
+#[test]
+fn shutdown_stops_top_level_actors_first() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (terminated_tx, mut terminated_rx) = mpsc::unbounded_channel();
+        let parent_id = system.spawn(parent, terminated_tx, Default::default()).await.unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+        let children = system
+            .all_actors()
+            .filter(|actor_id| futures::future::ready(*actor_id != parent_id))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(children.len(), 3);
+
+        let report = system.shutdown(Exit::shutdown(), Duration::from_secs(5)).await;
+        assert_eq!(report.top_level, vec![parent_id]);
+        assert!(report.is_clean(), "{:?}", report);
+
+        for expected in children.into_iter().rev().chain([parent_id]) {
+            assert_eq!(terminated_rx.recv().await.unwrap(), expected);
+        }
+        assert!(system.wait(parent_id).await.is_shutdown());
+        assert!(system.all_actors().collect::<Vec<_>>().await.is_empty());
+    })
+}
+
+#[test]
+fn shutdown_kills_stragglers() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let stubborn_id = system.spawn(stubborn, (), Default::default()).await.unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        let report = system.shutdown(Exit::shutdown(), SMALL_DELAY).await;
+        assert_eq!(report.top_level, vec![stubborn_id]);
+        assert_eq!(report.killed, vec![stubborn_id]);
+
+        assert!(system.wait(stubborn_id).await.is_kill());
+        assert!(system.all_actors().collect::<Vec<_>>().await.is_empty());
+    })
+}
+
+#[test]
+fn shutdown_reports_actors_unresponsive_to_kill() {
+    async fn blocking_the_thread(_context: &mut Context<Infallible>, _args: ()) {
+        tokio::time::sleep(SMALL_DELAY).await;
+        tokio::task::block_in_place(|| std::thread::sleep(SMALL_DELAY * 10));
+    }
+
+    common::run(async {
+        let system = System::new(SystemConfig {
+            actor_termination_timeout: SMALL_DELAY,
+            ..Default::default()
+        });
+        let actor = system.spawn(blocking_the_thread, (), Default::default()).await.unwrap();
+        tokio::time::sleep(SMALL_DELAY * 2).await;
+
+        let report = system.shutdown(Exit::shutdown(), SMALL_DELAY).await;
+        assert_eq!(report.killed, vec![actor]);
+        assert_eq!(report.unresponsive, vec![actor]);
+
+        system.wait(actor).await;
+        assert!(system.all_actors().collect::<Vec<_>>().await.is_empty());
+    })
+}
+
+#[test]
+fn no_spawn_after_shutdown() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let report = system.shutdown(Exit::shutdown(), SMALL_DELAY).await;
+        assert!(report.top_level.is_empty());
+        assert!(system.is_shutting_down());
+
+        assert!(matches!(
+            system.spawn(stubborn, (), Default::default()).await,
+            Err(SysSpawnError::ShuttingDown)
+        ));
+    })
+}
+
+#[test]
+fn actors_spawned_concurrently_with_shutdown_are_stopped() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let spawning = tokio::spawn({
+            let system = system.to_owned();
+            async move {
+                while system.spawn(stubborn, (), Default::default()).await.is_ok() {
+                    tokio::task::yield_now().await;
+                }
+            }
+        });
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        let report = system.shutdown(Exit::shutdown(), SMALL_DELAY).await;
+        assert!(report.unresponsive.is_empty(), "{:?}", report);
+        spawning.await.unwrap();
+
+        assert!(system.all_actors().collect::<Vec<_>>().await.is_empty());
+    })
+}
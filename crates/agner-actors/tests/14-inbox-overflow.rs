@@ -0,0 +1,171 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::exit_reason::BackendFailure;
+use agner_actors::system_error::{SendError, SysChannelError};
+use agner_actors::{ActorID, Context, Exit, InboxOverflow, SpawnOpts, System};
+use tokio::sync::{mpsc, oneshot};
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+const INBOX_SIZE: usize = 2;
+
+async fn slow_consumer(
+    context: &mut Context<usize>,
+    (go_rx, received_tx): (oneshot::Receiver<()>, mpsc::UnboundedSender<usize>),
+) -> Infallible {
+    let _ = go_rx.await;
+    loop {
+        let _ = received_tx.send(context.next_message().await);
+    }
+}
+
+async fn spawn_slow_consumer(
+    system: &System,
+    inbox_overflow: InboxOverflow,
+) -> (ActorID, oneshot::Sender<()>, mpsc::UnboundedReceiver<usize>) {
+    let (go_tx, go_rx) = oneshot::channel();
+    let (received_tx, received_rx) = mpsc::unbounded_channel();
+    let spawn_opts = SpawnOpts::new()
+        .with_msg_inbox_size(INBOX_SIZE)
+        .with_inbox_overflow(inbox_overflow);
+    let actor = system.spawn(slow_consumer, (go_rx, received_tx), spawn_opts).await.unwrap();
+    (actor, go_tx, received_rx)
+}
+
+async fn received(received_rx: &mut mpsc::UnboundedReceiver<usize>) -> Vec<usize> {
+    tokio::time::sleep(SMALL_DELAY).await;
+    let mut out = vec![];
+    while let Ok(item) = received_rx.try_recv() {
+        out.push(item);
+    }
+    out
+}
+
+#[test]
+fn crash_on_overflow() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (actor, _go_tx, _received_rx) =
+            spawn_slow_consumer(&system, InboxOverflow::Crash).await;
+
+        for i in 0..5usize {
+            system.send(actor, i).await;
+        }
+        let exit_reason = system.wait(actor).await;
+        assert!(matches!(exit_reason, Exit::Backend(BackendFailure::InboxFull("messages"))));
+    })
+}
+
+#[test]
+fn drop_newest_on_overflow() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (actor, go_tx, mut received_rx) =
+            spawn_slow_consumer(&system, InboxOverflow::DropNewest).await;
+
+        for i in 0..5usize {
+            system.send(actor, i).await;
+        }
+        tokio::time::sleep(SMALL_DELAY).await;
+        go_tx.send(()).unwrap();
+
+        assert_eq!(received(&mut received_rx).await, vec![0, 1]);
+        assert!(system.actor_info(actor).await.is_some());
+    })
+}
+
+#[test]
+fn drop_oldest_on_overflow() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (actor, go_tx, mut received_rx) =
+            spawn_slow_consumer(&system, InboxOverflow::DropOldest).await;
+
+        for i in 0..5usize {
+            system.send(actor, i).await;
+        }
+        tokio::time::sleep(SMALL_DELAY).await;
+        go_tx.send(()).unwrap();
+
+        assert_eq!(received(&mut received_rx).await, vec![3, 4]);
+        assert!(system.actor_info(actor).await.is_some());
+    })
+}
+
+#[test]
+fn reject_on_overflow() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (actor, go_tx, mut received_rx) =
+            spawn_slow_consumer(&system, InboxOverflow::Reject).await;
+
+        let mut accepted = vec![];
+        for i in 0..100 {
+            match system.try_send(actor, i).await {
+                Ok(()) => accepted.push(i),
+                Err(SendError::Full(rejected)) => {
+                    assert_eq!(rejected, i);
+                    break
+                },
+                Err(reason) => panic!("unexpected error: {}", reason),
+            }
+            tokio::time::sleep(SMALL_DELAY / 10).await;
+        }
+        assert_eq!(accepted.len(), INBOX_SIZE, "{:?}", accepted);
+
+        go_tx.send(()).unwrap();
+        assert_eq!(received(&mut received_rx).await, accepted);
+        assert!(system.actor_info(actor).await.is_some());
+    })
+}
+
+#[test]
+fn block_on_overflow() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (actor, go_tx, mut received_rx) =
+            spawn_slow_consumer(&system, InboxOverflow::Block).await;
+
+        assert!(matches!(system.channel::<usize>(actor).await, Err(SysChannelError::BoundedInbox)));
+        let chan = system.bounded_channel::<usize>(actor).await.unwrap();
+
+        let (sent_tx, mut sent_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for i in 0..10 {
+                chan.send(i).await.unwrap();
+                let _ = sent_tx.send(i);
+            }
+        });
+
+        tokio::time::sleep(SMALL_DELAY).await;
+        let mut sent = vec![];
+        while let Ok(i) = sent_rx.try_recv() {
+            sent.push(i);
+        }
+        assert_eq!(sent.len(), INBOX_SIZE, "{:?}", sent);
+
+        go_tx.send(()).unwrap();
+        while sent_rx.recv().await.is_some() {}
+        assert_eq!(received(&mut received_rx).await, (0..10).collect::<Vec<_>>());
+    })
+}
+
+#[test]
+fn try_send_reports_failures() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (actor, _go_tx, _received_rx) =
+            spawn_slow_consumer(&system, InboxOverflow::Crash).await;
+
+        assert!(matches!(
+            system.try_send(actor, "not a usize").await,
+            Err(SendError::InvalidMessageType("not a usize"))
+        ));
+
+        system.exit(actor, Exit::shutdown()).await;
+        system.wait(actor).await;
+        assert!(matches!(system.try_send(actor, 1usize).await, Err(SendError::NoActor(1))));
+    })
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::exit_reason::BackendFailure;
+use agner_actors::{ActorID, Context, Event, Exit, Signal, SignalOverflow, SpawnOpts, System};
+use tokio::sync::{mpsc, oneshot};
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+const SIG_INBOX_SIZE: usize = 2;
+const CHILDREN_COUNT: usize = 20;
+
+async fn child(_context: &mut Context<Infallible>, exit_rx: oneshot::Receiver<()>) -> Exit {
+    let _ = exit_rx.await;
+    Exit::from_message("child exited")
+}
+
+async fn slow_parent(
+    context: &mut Context<Infallible>,
+    (go_rx, signals_tx): (oneshot::Receiver<()>, mpsc::UnboundedSender<ActorID>),
+) -> Infallible {
+    context.trap_exit(true).await;
+    let _ = go_rx.await;
+    loop {
+        if let Event::Signal(Signal::Exit(from, _)) = context.next_event().await {
+            let _ = signals_tx.send(from);
+        }
+    }
+}
+
+async fn run_children_exits(
+    system: &System,
+    signal_overflow: SignalOverflow,
+) -> (ActorID, Vec<ActorID>, oneshot::Sender<()>, mpsc::UnboundedReceiver<ActorID>) {
+    let (go_tx, go_rx) = oneshot::channel();
+    let (signals_tx, signals_rx) = mpsc::unbounded_channel();
+    let spawn_opts = SpawnOpts::new()
+        .with_sig_inbox_size(SIG_INBOX_SIZE)
+        .with_signal_overflow(signal_overflow);
+    let parent = system.spawn(slow_parent, (go_rx, signals_tx), spawn_opts).await.unwrap();
+
+    let mut children = vec![];
+    let mut exit_txs = vec![];
+    for _ in 0..CHILDREN_COUNT {
+        let (exit_tx, exit_rx) = oneshot::channel::<()>();
+        let child = system.spawn(child, exit_rx, SpawnOpts::new().with_link(parent)).await.unwrap();
+        children.push(child);
+        exit_txs.push(exit_tx);
+    }
+    tokio::time::sleep(SMALL_DELAY).await;
+
+    exit_txs.into_iter().for_each(|exit_tx| {
+        let _ = exit_tx.send(());
+    });
+    for child in children.iter().copied() {
+        system.wait(child).await;
+    }
+    tokio::time::sleep(SMALL_DELAY).await;
+
+    (parent, children, go_tx, signals_rx)
+}
+
+#[test]
+fn signals_spill_when_inbox_is_full() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (parent, children, go_tx, mut signals_rx) =
+            run_children_exits(&system, SignalOverflow::Spill).await;
+
+        let info = system.actor_info(parent).await.expect("the parent should be alive");
+        assert_eq!(info.s_queue_len, (SIG_INBOX_SIZE, SIG_INBOX_SIZE));
+        assert_eq!(info.s_spilled_len, CHILDREN_COUNT - SIG_INBOX_SIZE);
+        assert_eq!(info.s_spilled_total, CHILDREN_COUNT - SIG_INBOX_SIZE);
+
+        go_tx.send(()).unwrap();
+        let mut received = HashSet::new();
+        for _ in 0..CHILDREN_COUNT {
+            received.insert(signals_rx.recv().await.unwrap());
+        }
+        assert_eq!(received, children.into_iter().collect());
+
+        let info = system.actor_info(parent).await.expect("the parent should be alive");
+        assert_eq!(info.s_spilled_len, 0);
+        assert_eq!(info.s_spilled_total, CHILDREN_COUNT - SIG_INBOX_SIZE);
+    })
+}
+
+#[test]
+fn signal_overflow_crashes_if_configured() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (parent, _children, _go_tx, _signals_rx) =
+            run_children_exits(&system, SignalOverflow::Crash).await;
+
+        let exit_reason = system.wait(parent).await;
+        assert!(matches!(exit_reason, Exit::Backend(BackendFailure::InboxFull("signals"))));
+    })
+}
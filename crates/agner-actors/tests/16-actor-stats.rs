@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use agner_actors::{Context, Event, Exit, System};
+use tokio::sync::oneshot;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+const MESSAGES_COUNT: u64 = 10;
+
+#[derive(Debug)]
+enum Request {
+    Echo(oneshot::Sender<()>),
+    SpawnJob(oneshot::Sender<()>),
+    JobDone,
+}
+
+async fn stats_subject(context: &mut Context<Request>, _args: ()) {
+    context.trap_exit(true).await;
+    loop {
+        match context.next_event().await {
+            Event::Message(Request::Echo(reply_to)) => {
+                let _ = reply_to.send(());
+            },
+            Event::Message(Request::SpawnJob(reply_to)) => {
+                context.future_to_inbox(async { Request::JobDone }).await;
+                let _ = reply_to.send(());
+            },
+            Event::Message(Request::JobDone) => (),
+            Event::Signal(_) => (),
+        }
+    }
+}
+
+async fn request(
+    system: &System,
+    actor: agner_actors::ActorID,
+    make_request: impl FnOnce(oneshot::Sender<()>) -> Request,
+) {
+    let (tx, rx) = oneshot::channel();
+    system.send(actor, make_request(tx)).await;
+    rx.await.unwrap();
+}
+
+#[test]
+fn actor_stats_are_collected() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let actor = system.spawn(stats_subject, (), Default::default()).await.unwrap();
+
+        for _ in 0..MESSAGES_COUNT {
+            request(&system, actor, Request::Echo).await;
+        }
+        request(&system, actor, Request::SpawnJob).await;
+
+        let linked = system
+            .spawn(
+                |_: &mut Context<()>, _: ()| async { Exit::from_message("bye") },
+                (),
+                Default::default(),
+            )
+            .await
+            .unwrap();
+        system.link(actor, linked).await;
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        let stats = system.actor_info(actor).await.unwrap().stats;
+
+        assert!(stats.uptime >= SMALL_DELAY);
+        assert_eq!(stats.messages_received, MESSAGES_COUNT + 2);
+        assert_eq!(stats.messages_processed, MESSAGES_COUNT + 2);
+        assert_eq!(stats.signals_received, 1);
+        assert_eq!(stats.jobs_spawned, 1);
+        assert_eq!(stats.jobs_completed, 1);
+
+        let since_last_message = stats.since_last_message.expect("no messages?");
+        assert!(since_last_message >= SMALL_DELAY);
+        assert!(since_last_message <= stats.uptime);
+
+        assert!(stats.polls_count > MESSAGES_COUNT);
+        assert!(stats.poll_time_max > Duration::ZERO);
+        assert!(stats.poll_time_total >= stats.poll_time_max);
+    })
+}
@@ -0,0 +1,120 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::exit_reason::BackendFailure;
+use agner_actors::{Context, Exit, SlowPollWatchdog, System, SystemConfig};
+use tokio::sync::oneshot;
+
+mod common;
+
+const THRESHOLD: Duration = Duration::from_millis(20);
+const SLOW_POLL: Duration = Duration::from_millis(50);
+const KILL_AFTER: usize = 3;
+
+#[derive(Debug)]
+enum Request {
+    Block(oneshot::Sender<()>),
+    BlockInJob,
+}
+
+async fn blocking_actor(context: &mut Context<Request>, _args: ()) -> Infallible {
+    loop {
+        match context.next_message().await {
+            Request::Block(reply_to) => {
+                std::thread::sleep(SLOW_POLL);
+                let _ = reply_to.send(());
+            },
+            Request::BlockInJob =>
+                context
+                    .spawn_job(async {
+                        std::thread::sleep(SLOW_POLL);
+                    })
+                    .await,
+        }
+    }
+}
+
+fn system_with_watchdog(watchdog: SlowPollWatchdog) -> System {
+    System::new(SystemConfig { slow_poll_watchdog: Some(watchdog), ..Default::default() })
+}
+
+#[test]
+fn slow_polls_are_counted() {
+    common::run(async {
+        let system = system_with_watchdog(SlowPollWatchdog::new(THRESHOLD));
+        let actor = system.spawn(blocking_actor, (), Default::default()).await.unwrap();
+
+        for _ in 0..KILL_AFTER * 2 {
+            let (tx, rx) = oneshot::channel();
+            system.send(actor, Request::Block(tx)).await;
+            rx.await.unwrap();
+        }
+
+        let stats = system.actor_info(actor).await.expect("the actor should be alive").stats;
+        assert_eq!(stats.slow_polls, KILL_AFTER * 2);
+        assert!(stats.poll_time_max >= SLOW_POLL);
+    })
+}
+
+#[test]
+fn slow_polls_are_not_counted_without_watchdog() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let actor = system.spawn(blocking_actor, (), Default::default()).await.unwrap();
+
+        let (tx, rx) = oneshot::channel();
+        system.send(actor, Request::Block(tx)).await;
+        rx.await.unwrap();
+
+        let stats = system.actor_info(actor).await.expect("the actor should be alive").stats;
+        assert_eq!(stats.slow_polls, 0);
+    })
+}
+
+#[test]
+fn actor_is_killed_after_repeated_slow_polls() {
+    common::run(async {
+        let system =
+            system_with_watchdog(SlowPollWatchdog::new(THRESHOLD).with_kill_after(KILL_AFTER));
+        let actor = system.spawn(blocking_actor, (), Default::default()).await.unwrap();
+
+        for _ in 0..KILL_AFTER {
+            let (tx, rx) = oneshot::channel();
+            system.send(actor, Request::Block(tx)).await;
+            rx.await.unwrap();
+        }
+
+        let exit_reason = system.wait(actor).await;
+        assert!(
+            matches!(
+                exit_reason,
+                Exit::Backend(BackendFailure::SlowPoll {
+                    violations: KILL_AFTER,
+                    threshold: THRESHOLD
+                })
+            ),
+            "{}",
+            exit_reason
+        );
+    })
+}
+
+#[test]
+fn slow_jobs_are_watched_too() {
+    common::run(async {
+        let system =
+            system_with_watchdog(SlowPollWatchdog::new(THRESHOLD).with_kill_after(KILL_AFTER));
+        let actor = system.spawn(blocking_actor, (), Default::default()).await.unwrap();
+
+        for _ in 0..KILL_AFTER {
+            system.send(actor, Request::BlockInJob).await;
+        }
+
+        let exit_reason = system.wait(actor).await;
+        assert!(
+            matches!(exit_reason, Exit::Backend(BackendFailure::SlowPoll { .. })),
+            "{}",
+            exit_reason
+        );
+    })
+}
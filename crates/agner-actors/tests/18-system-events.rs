@@ -0,0 +1,109 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::{ActorID, Context, Exit, System, SystemConfig, SystemEvent};
+use futures::{Stream, StreamExt};
+use tokio::sync::oneshot;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+async fn trapping_actor(context: &mut Context<Infallible>, linked: ActorID) -> Infallible {
+    context.trap_exit(true).await;
+    context.link(linked).await;
+    std::future::pending().await
+}
+
+async fn exit_on_request(
+    _context: &mut Context<Infallible>,
+    exit_rx: oneshot::Receiver<()>,
+) -> Exit {
+    let _ = exit_rx.await;
+    Exit::from_message("requested")
+}
+
+async fn collect_events(events: impl Stream<Item = SystemEvent> + Unpin) -> Vec<SystemEvent> {
+    events.take_until(tokio::time::sleep(SMALL_DELAY)).collect().await
+}
+
+#[test]
+fn lifecycle_events_are_published() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let events = system.subscribe_events();
+
+        let (exit_tx, exit_rx) = oneshot::channel();
+        let linked = system.spawn(exit_on_request, exit_rx, Default::default()).await.unwrap();
+        let trapping = system.spawn(trapping_actor, linked, Default::default()).await.unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+        exit_tx.send(()).unwrap();
+        system.wait(linked).await;
+
+        let events = collect_events(Box::pin(events)).await;
+        let has = |f: &dyn Fn(&SystemEvent) -> bool| events.iter().any(f);
+
+        assert!(has(
+            &|e| matches!(e, SystemEvent::Spawned { actor_id, .. } if *actor_id == linked)
+        ));
+        assert!(has(
+            &|e| matches!(e, SystemEvent::Spawned { actor_id, .. } if *actor_id == trapping)
+        ));
+        assert!(has(&|e| matches!(
+            e,
+            SystemEvent::TrapExit { actor_id, trap_exit: true } if *actor_id == trapping
+        )));
+        assert!(has(&|e| matches!(
+            e,
+            SystemEvent::Linked { actor_id, to } if *actor_id == trapping && *to == linked
+        )));
+        assert!(has(&|e| matches!(
+            e,
+            SystemEvent::Linked { actor_id, to } if *actor_id == linked && *to == trapping
+        )));
+        assert!(has(&|e| matches!(
+            e,
+            SystemEvent::Exited { actor_id, exit_reason } if *actor_id == linked && !exit_reason.is_normal()
+        )));
+        assert!(!has(
+            &|e| matches!(e, SystemEvent::Exited { actor_id, .. } if *actor_id == trapping)
+        ));
+        assert!(!has(&|e| matches!(e, SystemEvent::Lagged { .. })));
+
+        let spawned_at = events.iter().position(
+            |e| matches!(e, SystemEvent::Spawned { actor_id, .. } if *actor_id == linked),
+        );
+        let exited_at = events
+            .iter()
+            .position(|e| matches!(e, SystemEvent::Exited { actor_id, .. } if *actor_id == linked));
+        assert!(spawned_at < exited_at);
+    })
+}
+
+#[test]
+fn slow_subscriber_is_notified_of_lag() {
+    common::run(async {
+        const BUFFER_SIZE: usize = 4;
+        const ACTORS_COUNT: usize = 10;
+
+        let system =
+            System::new(SystemConfig { events_buffer_size: BUFFER_SIZE, ..Default::default() });
+        let events = system.subscribe_events();
+
+        for _ in 0..ACTORS_COUNT {
+            let actor = system
+                .spawn(|_: &mut Context<Infallible>, _: ()| async {}, (), Default::default())
+                .await
+                .unwrap();
+            system.wait(actor).await;
+        }
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        let events = collect_events(Box::pin(events)).await;
+        let SystemEvent::Lagged { missed } = events[0] else {
+            panic!("expected Lagged, got {:?}", events[0])
+        };
+        assert_eq!(missed as usize + events.len() - 1, ACTORS_COUNT * 2);
+        assert_eq!(events.len() - 1, BUFFER_SIZE);
+    })
+}
@@ -0,0 +1,138 @@
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use agner_actors::dead_letter_handlers::DeadLetterCallback;
+use agner_actors::system_error::SendError;
+use agner_actors::{
+    ActorID, Context, DeadLetter, DeadLetterReason, Exit, InboxOverflow, SpawnOpts, System,
+    SystemConfig,
+};
+use tokio::sync::{mpsc, oneshot};
+
+mod common;
+
+async fn idle(_context: &mut Context<usize>, _args: ()) -> Infallible {
+    std::future::pending().await
+}
+
+async fn spawn_and_stop(system: &System) -> ActorID {
+    let actor = system.spawn(idle, (), Default::default()).await.unwrap();
+    system.exit(actor, Exit::shutdown()).await;
+    system.wait(actor).await;
+    actor
+}
+
+#[test]
+fn dead_letters_are_sent_to_channel() {
+    common::run(async {
+        let (dead_letters_tx, mut dead_letters_rx) = mpsc::unbounded_channel::<DeadLetter>();
+        let system = System::new(SystemConfig {
+            dead_letter_handler: Arc::new(dead_letters_tx),
+            ..Default::default()
+        });
+
+        let alive = system.spawn(idle, (), Default::default()).await.unwrap();
+        system.send(alive, "not a usize").await;
+
+        let dead_letter = dead_letters_rx.recv().await.unwrap();
+        assert_eq!(dead_letter.to, alive);
+        assert_eq!(dead_letter.reason, DeadLetterReason::InvalidMessageType);
+        assert_eq!(dead_letter.type_name, std::any::type_name::<&'static str>());
+        assert_eq!(dead_letter.downcast::<&'static str>().unwrap(), "not a usize");
+
+        let gone = spawn_and_stop(&system).await;
+        system.send(gone, 42usize).await;
+
+        let dead_letter = dead_letters_rx.recv().await.unwrap();
+        assert_eq!(dead_letter.to, gone);
+        assert_eq!(dead_letter.reason, DeadLetterReason::NoActor);
+        let dead_letter = dead_letter.downcast::<String>().unwrap_err();
+        assert_eq!(dead_letter.downcast::<usize>().unwrap(), 42);
+
+        system.send(alive, 1usize).await;
+        assert!(dead_letters_rx.try_recv().is_err());
+    })
+}
+
+#[test]
+fn dead_letters_are_passed_to_callback() {
+    common::run(async {
+        let reasons = Arc::new(Mutex::new(vec![]));
+        let callback = {
+            let reasons = reasons.to_owned();
+            DeadLetterCallback(move |dead_letter: DeadLetter| {
+                reasons.lock().unwrap().push(dead_letter.reason)
+            })
+        };
+        let system = System::new(SystemConfig {
+            dead_letter_handler: Arc::new(callback),
+            ..Default::default()
+        });
+
+        let (_go_tx, go_rx) = oneshot::channel::<()>();
+        let full = system
+            .spawn(
+                |_: &mut Context<usize>, go_rx: oneshot::Receiver<()>| async move {
+                    let _ = go_rx.await;
+                },
+                go_rx,
+                SpawnOpts::new()
+                    .with_msg_inbox_size(1)
+                    .with_inbox_overflow(InboxOverflow::Reject),
+            )
+            .await
+            .unwrap();
+        for i in 0..10usize {
+            system.send(full, i).await;
+        }
+
+        assert!(reasons.lock().unwrap().contains(&DeadLetterReason::InboxFull));
+    })
+}
+
+#[test]
+fn messages_dropped_on_overflow_are_dead_letters() {
+    common::run(async {
+        let (dead_letters_tx, mut dead_letters_rx) = mpsc::unbounded_channel::<DeadLetter>();
+        let system = System::new(SystemConfig {
+            dead_letter_handler: Arc::new(dead_letters_tx),
+            ..Default::default()
+        });
+
+        for (inbox_overflow, expected_dropped) in
+            [(InboxOverflow::DropNewest, [1, 2]), (InboxOverflow::DropOldest, [0, 1])]
+        {
+            let spawn_opts =
+                SpawnOpts::new().with_msg_inbox_size(1).with_inbox_overflow(inbox_overflow);
+            let actor = system.spawn(idle, (), spawn_opts).await.unwrap();
+            for i in 0..3usize {
+                system.send(actor, i).await;
+            }
+
+            for expected in expected_dropped {
+                let dead_letter = dead_letters_rx.recv().await.unwrap();
+                assert_eq!(dead_letter.to, actor);
+                assert_eq!(dead_letter.reason, DeadLetterReason::InboxOverflow);
+                assert_eq!(dead_letter.downcast::<usize>().unwrap(), expected);
+            }
+            assert!(dead_letters_rx.try_recv().is_err());
+        }
+    })
+}
+
+#[test]
+fn send_checked_gives_the_message_back() {
+    common::run(async {
+        let system = System::new(Default::default());
+
+        let alive = system.spawn(idle, (), Default::default()).await.unwrap();
+        assert!(system.send_checked(alive, 1usize).await.is_ok());
+        assert!(matches!(
+            system.send_checked(alive, "not a usize").await,
+            Err(SendError::InvalidMessageType("not a usize"))
+        ));
+
+        let gone = spawn_and_stop(&system).await;
+        assert!(matches!(system.send_checked(gone, 2usize).await, Err(SendError::NoActor(2))));
+    })
+}
@@ -0,0 +1,116 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::system_error::{CallError, SendError, SysChannelError};
+use agner_actors::{ActorID, ActorRef, Context, Event, Exit, ReplyTo, Signal, System};
+use tokio::sync::oneshot;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+const CALL_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+enum Request {
+    Double(usize, ReplyTo<usize>),
+    WhoAmI(ReplyTo<ActorRef<Request>>),
+    Exit(Exit),
+}
+
+async fn doubler(context: &mut Context<Request>, _args: ()) -> Exit {
+    loop {
+        match context.next_message().await {
+            Request::Double(n, reply_to) => {
+                let _ = reply_to.reply(n * 2);
+            },
+            Request::WhoAmI(reply_to) => {
+                let _ = reply_to.reply(context.myself());
+            },
+            Request::Exit(exit_reason) => break exit_reason,
+        }
+    }
+}
+
+async fn exit_watcher(
+    context: &mut Context<Infallible>,
+    exited_tx: oneshot::Sender<ActorID>,
+) -> Infallible {
+    context.trap_exit(true).await;
+    loop {
+        if let Event::Signal(Signal::Exit(from, _)) = context.next_event().await {
+            let _ = exited_tx.send(from);
+            break std::future::pending().await
+        }
+    }
+}
+
+#[test]
+fn actor_ref_send_and_call() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let actor: ActorRef<Request> =
+            system.spawn_typed(doubler, (), Default::default()).await.unwrap();
+
+        let four = actor.call(|reply_to| Request::Double(2, reply_to), CALL_TIMEOUT).await.unwrap();
+        assert_eq!(four, 4);
+
+        let myself = actor.call(Request::WhoAmI, CALL_TIMEOUT).await.expect("failed to get myself");
+        assert_eq!(myself, actor);
+        assert_eq!(myself.to_string(), actor.actor_id().to_string());
+
+        actor.send(Request::Exit(Exit::from_message("bye"))).await.unwrap();
+
+        let exit_reason = actor.wait().await;
+        assert!(!exit_reason.is_normal());
+
+        assert!(matches!(
+            actor.send(Request::Exit(Exit::normal())).await,
+            Err(SendError::NoActor(Request::Exit(_)))
+        ));
+        assert!(matches!(
+            actor.call(|reply_to| Request::Double(2, reply_to), CALL_TIMEOUT).await,
+            Err(CallError::NoActor)
+        ));
+    })
+}
+
+#[test]
+fn actor_ref_link() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let actor = system.spawn_typed(doubler, (), Default::default()).await.unwrap();
+
+        let (exited_tx, exited_rx) = oneshot::channel();
+        let watcher = system.spawn(exit_watcher, exited_tx, Default::default()).await.unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+        actor.link(watcher).await;
+
+        actor.send(Request::Exit(Exit::from_message("bye"))).await.unwrap();
+        assert_eq!(exited_rx.await.unwrap(), ActorID::from(&actor));
+    })
+}
+
+#[test]
+fn actor_ref_from_actor_id() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let actor_id = system.spawn(doubler, (), Default::default()).await.unwrap();
+
+        assert!(matches!(
+            system.actor_ref::<usize>(actor_id).await,
+            Err(SysChannelError::InvalidMessageType)
+        ));
+
+        let actor = system.actor_ref::<Request>(actor_id).await.unwrap();
+        assert_eq!(actor.actor_id(), actor_id);
+        let six = actor.call(|reply_to| Request::Double(3, reply_to), CALL_TIMEOUT).await.unwrap();
+        assert_eq!(six, 6);
+
+        actor.send(Request::Exit(Exit::normal())).await.unwrap();
+        actor.wait().await;
+        assert!(matches!(
+            system.actor_ref::<Request>(actor_id).await,
+            Err(SysChannelError::NoActor)
+        ));
+    })
+}
@@ -0,0 +1,147 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::system_error::{SendError, SysChannelError, SysSpawnError};
+use agner_actors::{Context, InboxOverflow, SpawnOpts, System};
+use tokio::sync::{mpsc, oneshot};
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Message {
+    Text(String),
+    Number(usize),
+}
+
+impl From<String> for Message {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+impl From<usize> for Message {
+    fn from(number: usize) -> Self {
+        Self::Number(number)
+    }
+}
+
+async fn forwarder(
+    context: &mut Context<Message>,
+    received_tx: mpsc::UnboundedSender<Message>,
+) -> Infallible {
+    loop {
+        let _ = received_tx.send(context.next_message().await);
+    }
+}
+
+async fn late_adopter(
+    context: &mut Context<Message>,
+    (ready_tx, received_tx): (oneshot::Sender<()>, mpsc::UnboundedSender<Message>),
+) -> Infallible {
+    context.add_adapter::<usize>().await;
+    let _ = ready_tx.send(());
+    forwarder(context, received_tx).await
+}
+
+#[test]
+fn adapters_registered_at_spawn() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (received_tx, mut received_rx) = mpsc::unbounded_channel();
+        let spawn_opts = SpawnOpts::new()
+            .with_adapter::<String, Message>()
+            .with_adapter::<usize, Message>();
+        let actor = system.spawn(forwarder, received_tx, spawn_opts).await.unwrap();
+
+        system.send(actor, "hello".to_owned()).await;
+        system.send(actor, 42usize).await;
+        system.send(actor, Message::Number(1)).await;
+        assert!(matches!(
+            system.send_checked(actor, "unknown").await,
+            Err(SendError::InvalidMessageType("unknown"))
+        ));
+
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Text("hello".to_owned()));
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Number(42));
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Number(1));
+
+        let chan = system.channel::<usize>(actor).await.unwrap();
+        chan.send(5).unwrap();
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Number(5));
+        assert!(system.channel::<Message>(actor).await.is_ok());
+
+        let chan = system.bounded_channel::<usize>(actor).await.unwrap();
+        chan.send(7).await.unwrap();
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Number(7));
+
+        let typed = system.actor_ref::<String>(actor).await.unwrap();
+        typed.send("typed".to_owned()).await.unwrap();
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Text("typed".to_owned()));
+    })
+}
+
+#[test]
+fn adapter_for_another_message_type_fails_spawn() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (received_tx, _received_rx) = mpsc::unbounded_channel();
+        let spawn_opts = SpawnOpts::new().with_adapter::<usize, Option<usize>>();
+        assert!(matches!(
+            system.spawn(forwarder, received_tx, spawn_opts).await,
+            Err(SysSpawnError::InvalidAdapter(_))
+        ));
+    })
+}
+
+#[test]
+fn adapters_added_by_actor() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (ready_tx, ready_rx) = oneshot::channel();
+        let (received_tx, mut received_rx) = mpsc::unbounded_channel();
+        let actor = system
+            .spawn(late_adopter, (ready_tx, received_tx), Default::default())
+            .await
+            .unwrap();
+
+        ready_rx.await.unwrap();
+        system.send(actor, 3usize).await;
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Number(3));
+        assert!(matches!(
+            system.send_checked(actor, "text".to_owned()).await,
+            Err(SendError::InvalidMessageType(_))
+        ));
+    })
+}
+
+#[test]
+fn rejected_adapted_message_is_given_back() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let actor = system
+            .spawn(
+                |_: &mut Context<Message>, _: ()| std::future::pending::<Infallible>(),
+                (),
+                SpawnOpts::new()
+                    .with_msg_inbox_size(1)
+                    .with_inbox_overflow(InboxOverflow::Reject)
+                    .with_adapter::<usize, Message>(),
+            )
+            .await
+            .unwrap();
+
+        let mut rejected = None;
+        for i in 0..100usize {
+            if let Err(SendError::Full(message)) = system.try_send(actor, i).await {
+                rejected = Some((i, message));
+                break
+            }
+            tokio::time::sleep(SMALL_DELAY / 10).await;
+        }
+        let (sent, rejected) = rejected.expect("the inbox should get full");
+        assert_eq!(sent, rejected);
+
+        assert!(matches!(system.channel::<usize>(actor).await, Err(SysChannelError::BoundedInbox)));
+    })
+}
@@ -0,0 +1,120 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use agner_actors::system_error::SendError;
+use agner_actors::{Context, Exit, InboxOverflow, SpawnOpts, System};
+use tokio::sync::{mpsc, oneshot};
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Message {
+    Work(usize),
+    Drain,
+}
+
+async fn recorder(
+    context: &mut Context<Message>,
+    (go_rx, received_tx): (oneshot::Receiver<()>, mpsc::UnboundedSender<Message>),
+) -> Infallible {
+    let _ = go_rx.await;
+    loop {
+        let _ = received_tx.send(context.next_message().await);
+    }
+}
+
+#[test]
+fn urgent_messages_overtake_regular_ones() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (go_tx, go_rx) = oneshot::channel();
+        let (received_tx, mut received_rx) = mpsc::unbounded_channel();
+        let actor = system.spawn(recorder, (go_rx, received_tx), Default::default()).await.unwrap();
+
+        for i in 0..5 {
+            system.send(actor, Message::Work(i)).await;
+        }
+        system.send_urgent(actor, Message::Drain).await;
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        let info = system.actor_info(actor).await.unwrap();
+        assert_eq!(info.m_queue_len.0, 5);
+        assert_eq!(info.u_queue_len.0, 1);
+        assert_eq!(info.stats.urgent_received, 1);
+        assert_eq!(info.stats.urgent_processed, 0);
+
+        go_tx.send(()).unwrap();
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Drain);
+        for i in 0..5 {
+            assert_eq!(received_rx.recv().await.unwrap(), Message::Work(i));
+        }
+
+        let info = system.actor_info(actor).await.unwrap();
+        assert_eq!(info.stats.urgent_processed, 1);
+        assert_eq!(info.stats.messages_processed, 5);
+    })
+}
+
+#[test]
+fn urgent_lane_ignores_inbox_overflow() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (go_tx, go_rx) = oneshot::channel();
+        let (received_tx, mut received_rx) = mpsc::unbounded_channel();
+        let spawn_opts = SpawnOpts::new()
+            .with_msg_inbox_size(2)
+            .with_inbox_overflow(InboxOverflow::Reject);
+        let actor = system.spawn_typed(recorder, (go_rx, received_tx), spawn_opts).await.unwrap();
+        let chan = system.bounded_channel::<Message>(actor.actor_id()).await.unwrap();
+
+        let mut rejected = false;
+        for i in 0..100 {
+            if let Err(SendError::Full(_)) = chan.try_send(Message::Work(i)) {
+                rejected = true;
+                break
+            }
+            tokio::time::sleep(SMALL_DELAY / 10).await;
+        }
+        assert!(rejected);
+
+        for _ in 0..10 {
+            actor.send_urgent(Message::Drain).unwrap();
+        }
+        chan.send_urgent(Message::Drain).unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+        let info = system.actor_info(actor.actor_id()).await.unwrap();
+        assert_eq!(info.u_queue_len.0, 11);
+        assert_eq!(info.stats.urgent_received, 11);
+
+        go_tx.send(()).unwrap();
+        for _ in 0..11 {
+            assert_eq!(received_rx.recv().await.unwrap(), Message::Drain);
+        }
+        assert!(matches!(received_rx.recv().await.unwrap(), Message::Work(_)));
+
+        system.exit(actor.actor_id(), Exit::shutdown()).await;
+        actor.wait().await;
+        assert!(matches!(actor.send_urgent(Message::Drain), Err(SendError::NoActor(_))));
+    })
+}
+
+#[test]
+fn urgent_messages_via_channel() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (go_tx, go_rx) = oneshot::channel();
+        let (received_tx, mut received_rx) = mpsc::unbounded_channel();
+        let actor = system.spawn(recorder, (go_rx, received_tx), Default::default()).await.unwrap();
+        let chan = system.channel::<Message>(actor).await.unwrap();
+
+        chan.send(Message::Work(1)).unwrap();
+        chan.send_urgent(Message::Drain).unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        go_tx.send(()).unwrap();
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Drain);
+        assert_eq!(received_rx.recv().await.unwrap(), Message::Work(1));
+    })
+}
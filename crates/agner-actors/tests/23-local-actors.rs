@@ -0,0 +1,230 @@
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::rc::Rc;
+use std::time::Duration;
+
+use agner_actors::exit_reason::{BackendFailure, WellKnown};
+use agner_actors::{Context, Exit, ReplyTo, System};
+use futures::StreamExt;
+use tokio::task::LocalSet;
+
+mod common;
+
+const CALL_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+enum Request {
+    Push(usize),
+    Sum(ReplyTo<usize>),
+}
+
+/// Keeps its state in an `Rc<RefCell<_>>`, thus cannot be spawned via `System::spawn`.
+async fn rc_accumulator(context: &mut Context<Request>, state: Rc<RefCell<Vec<usize>>>) -> Exit {
+    loop {
+        match context.next_message().await {
+            Request::Push(n) => state.borrow_mut().push(n),
+            Request::Sum(reply_to) => {
+                let _ = reply_to.reply(state.borrow().iter().sum());
+            },
+        }
+    }
+}
+
+async fn failing(context: &mut Context<()>, _args: ()) -> Exit {
+    context.next_message().await;
+    Exit::from_message("failed")
+}
+
+fn is_dropped(exit_reason: &Exit) -> bool {
+    matches!(exit_reason, Exit::Backend(BackendFailure::Dropped))
+}
+
+fn is_linked_to_dropped(exit_reason: &Exit) -> bool {
+    matches!(exit_reason, Exit::Standard(WellKnown::Linked(_, linked)) if is_dropped(linked))
+}
+
+#[test]
+fn local_actor_is_addressable() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let local_set = LocalSet::new();
+        let state = Rc::new(RefCell::new(vec![]));
+
+        let actor = system
+            .spawn_local(&local_set, rc_accumulator, state.to_owned(), Default::default())
+            .await
+            .unwrap();
+
+        local_set
+            .run_until(async {
+                let sender = tokio::spawn({
+                    let system = system.to_owned();
+                    let actor_id = actor.actor_id();
+                    async move {
+                        for n in 1..=10 {
+                            system.send(actor_id, Request::Push(n)).await;
+                        }
+                    }
+                });
+                sender.await.unwrap();
+
+                let sum = actor.call(Request::Sum, CALL_TIMEOUT).await.unwrap();
+                assert_eq!(sum, 55);
+            })
+            .await;
+
+        assert_eq!(state.borrow().len(), 10);
+        let actor_id = actor.actor_id();
+        assert!(system.all_actors().any(|id| async move { id == actor_id }).await);
+    })
+}
+
+#[test]
+fn local_actor_is_linkable() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let local_set = LocalSet::new();
+
+        let local_actor = system
+            .spawn_local(&local_set, rc_accumulator, Default::default(), Default::default())
+            .await
+            .unwrap();
+        let regular_actor = system.spawn(failing, (), Default::default()).await.unwrap();
+        system.link(local_actor.actor_id(), regular_actor).await;
+
+        local_set
+            .run_until(async {
+                system.send(regular_actor, ()).await;
+                let exit_reason = local_actor.wait().await;
+                assert!(exit_reason.is_linked(), "{}", exit_reason);
+            })
+            .await;
+    })
+}
+
+#[test]
+fn local_actor_spawns_local_actors() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let local_set = Rc::new(LocalSet::new());
+
+        async fn parent(
+            context: &mut Context<Infallible>,
+            (local_set, reply_to): (Rc<LocalSet>, tokio::sync::oneshot::Sender<usize>),
+        ) -> Exit {
+            let child = context
+                .system()
+                .spawn_local(&local_set, rc_accumulator, Default::default(), Default::default())
+                .await
+                .unwrap();
+            child.send(Request::Push(42)).await.unwrap();
+            let sum = child.call(Request::Sum, CALL_TIMEOUT).await.unwrap();
+            let _ = reply_to.send(sum);
+            Exit::normal()
+        }
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        system
+            .spawn_local(&local_set, parent, (local_set.to_owned(), tx), Default::default())
+            .await
+            .unwrap();
+        assert_eq!(local_set.run_until(rx).await.unwrap(), 42);
+    })
+}
+
+#[test]
+fn local_actor_exits_when_its_undriven_local_set_is_dropped() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let local_set = LocalSet::new();
+
+        let local_actor = system
+            .spawn_local(&local_set, rc_accumulator, Default::default(), Default::default())
+            .await
+            .unwrap();
+        let regular_actor = system.spawn(failing, (), Default::default()).await.unwrap();
+        system.link(local_actor.actor_id(), regular_actor).await;
+
+        std::mem::drop(local_set);
+
+        let exit_reason = local_actor.wait().await;
+        assert!(is_dropped(&exit_reason), "{}", exit_reason);
+        let exit_reason = system.wait(regular_actor).await;
+        assert!(is_linked_to_dropped(&exit_reason), "{}", exit_reason);
+        assert!(system.all_actors().collect::<Vec<_>>().await.is_empty());
+    })
+}
+
+#[test]
+fn local_actor_exits_when_its_local_set_is_dropped_while_running() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let local_set = LocalSet::new();
+
+        let local_actor = system
+            .spawn_local(&local_set, rc_accumulator, Default::default(), Default::default())
+            .await
+            .unwrap();
+        let regular_actor = system.spawn(failing, (), Default::default()).await.unwrap();
+        system.link(local_actor.actor_id(), regular_actor).await;
+
+        local_set
+            .run_until(async {
+                local_actor.send(Request::Push(1)).await.unwrap();
+                let sum = local_actor.call(Request::Sum, CALL_TIMEOUT).await.unwrap();
+                assert_eq!(sum, 1);
+            })
+            .await;
+        std::mem::drop(local_set);
+
+        let exit_reason = local_actor.wait().await;
+        assert!(is_dropped(&exit_reason), "{}", exit_reason);
+        let exit_reason = system.wait(regular_actor).await;
+        assert!(is_linked_to_dropped(&exit_reason), "{}", exit_reason);
+    })
+}
+
+#[test]
+fn every_linked_actor_is_notified_when_the_local_set_is_dropped() {
+    common::run(async {
+        const LINKED: usize = 8;
+
+        let system = System::new(Default::default());
+        let local_set = LocalSet::new();
+
+        let local_actor = system
+            .spawn_local(&local_set, rc_accumulator, Default::default(), Default::default())
+            .await
+            .unwrap();
+
+        let mut linked_actors = vec![];
+        for _ in 0..LINKED / 2 {
+            let regular_actor = system.spawn(failing, (), Default::default()).await.unwrap();
+            system.link(local_actor.actor_id(), regular_actor).await;
+            linked_actors.push(regular_actor);
+        }
+        // the links above are taken by the local actor while it runs...
+        local_set
+            .run_until(async {
+                let sum = local_actor.call(Request::Sum, CALL_TIMEOUT).await.unwrap();
+                assert_eq!(sum, 0);
+            })
+            .await;
+        // ... and these are still pending in its sys-msg channel when it is dropped.
+        for _ in LINKED / 2..LINKED {
+            let regular_actor = system.spawn(failing, (), Default::default()).await.unwrap();
+            system.link(local_actor.actor_id(), regular_actor).await;
+            linked_actors.push(regular_actor);
+        }
+
+        std::mem::drop(local_set);
+
+        let exit_reason = local_actor.wait().await;
+        assert!(is_dropped(&exit_reason), "{}", exit_reason);
+        for regular_actor in linked_actors {
+            let exit_reason = system.wait(regular_actor).await;
+            assert!(is_linked_to_dropped(&exit_reason), "{}: {}", regular_actor, exit_reason);
+        }
+        assert!(system.all_actors().collect::<Vec<_>>().await.is_empty());
+    })
+}
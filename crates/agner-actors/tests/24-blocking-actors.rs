@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use agner_actors::{
+    ActorID, Blocking, BlockingContext, Context, Event, Exit, ReplyTo, Signal, SpawnOpts, System,
+    SystemConfig,
+};
+use tokio::sync::oneshot;
+
+mod common;
+
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+const CALL_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+enum Request {
+    Double(usize, ReplyTo<usize>),
+    Forward(ActorID, String),
+    Panic,
+    Exit(Exit),
+}
+
+fn doubler(context: &mut BlockingContext<Request>, _args: ()) -> Exit {
+    while let Some(request) = context.recv_blocking() {
+        match request {
+            Request::Double(n, reply_to) => {
+                let _ = reply_to.reply(n * 2);
+            },
+            Request::Forward(to, text) => context.send_blocking(to, text),
+            Request::Panic => panic!("asked to panic"),
+            Request::Exit(exit_reason) => {
+                context.exit(exit_reason);
+                break
+            },
+        }
+    }
+    Exit::normal()
+}
+
+fn exit_watcher(
+    context: &mut BlockingContext<String>,
+    (linked_to, exited_tx): (ActorID, oneshot::Sender<(ActorID, Exit)>),
+) -> Exit {
+    context.trap_exit_blocking(true);
+    context.link_blocking(linked_to);
+    while let Some(event) = context.next_event_blocking() {
+        if let Event::Signal(Signal::Exit(from, exit_reason)) = event {
+            let _ = exited_tx.send((from, exit_reason));
+            break
+        }
+    }
+    Exit::normal()
+}
+
+async fn receiver(context: &mut Context<String>, forwarded_tx: oneshot::Sender<String>) -> Exit {
+    let _ = forwarded_tx.send(context.next_message().await);
+    Exit::normal()
+}
+
+#[test]
+fn blocking_actor_receives_and_replies() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let actor = system.spawn_blocking(doubler, (), Default::default()).await.unwrap();
+
+        let four = actor.call(|reply_to| Request::Double(2, reply_to), CALL_TIMEOUT).await.unwrap();
+        assert_eq!(four, 4);
+
+        let (forwarded_tx, forwarded_rx) = oneshot::channel();
+        let receiver = system.spawn(receiver, forwarded_tx, Default::default()).await.unwrap();
+        actor.send(Request::Forward(receiver, "hello".to_owned())).await.unwrap();
+        assert_eq!(forwarded_rx.await.unwrap(), "hello");
+
+        actor.send(Request::Exit(Exit::from_message("bye"))).await.unwrap();
+        let exit_reason = actor.wait().await;
+        assert_eq!(exit_reason.to_string(), Exit::from_message("bye").to_string());
+    })
+}
+
+#[test]
+fn blocking_actor_panic_is_caught() {
+    common::run(async {
+        let system = System::new(SystemConfig { panic_locations: true, ..Default::default() });
+        let actor = system.spawn_blocking(doubler, (), Default::default()).await.unwrap();
+
+        actor.send(Request::Panic).await.unwrap();
+        let Exit::Panic { message, location } = actor.wait().await else { panic!("not a panic") };
+        assert_eq!(message.as_ref(), "asked to panic");
+        let location = location.as_deref().expect("no location");
+        assert!(location.contains("24-blocking-actors.rs"), "{}", location);
+    })
+}
+
+#[test]
+fn blocking_actor_participates_in_links() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let actor = system.spawn_blocking(doubler, (), Default::default()).await.unwrap();
+
+        let (exited_tx, exited_rx) = oneshot::channel();
+        let watcher = system
+            .spawn(Blocking(exit_watcher), (actor.actor_id(), exited_tx), SpawnOpts::new())
+            .await
+            .unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        system.exit(actor.actor_id(), Exit::shutdown()).await;
+        let (from, exit_reason) = exited_rx.await.unwrap();
+        assert_eq!(from, actor.actor_id());
+        assert!(exit_reason.is_shutdown(), "{:?}", exit_reason);
+
+        assert!(system.wait(watcher).await.is_normal());
+    })
+}
+
+#[test]
+fn killed_blocking_actor_stops_receiving() {
+    common::run(async {
+        let system = System::new(Default::default());
+        let (done_tx, done_rx) = oneshot::channel();
+        let actor = system
+            .spawn(
+                Blocking(|context: &mut BlockingContext<()>, done_tx: oneshot::Sender<()>| {
+                    while context.recv_blocking().is_some() {}
+                    let _ = done_tx.send(());
+                    Exit::normal()
+                }),
+                done_tx,
+                Default::default(),
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(SMALL_DELAY).await;
+
+        system.exit(actor, Exit::kill()).await;
+        assert!(system.wait(actor).await.is_kill());
+        done_rx.await.unwrap();
+    })
+}
@@ -0,0 +1,72 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use agner_actors::spawners::AmbientSpawner;
+use agner_actors::{Context, Exit, ReplyTo, Spawner, System, SystemConfig};
+use futures::future::BoxFuture;
+
+mod common;
+
+const CALL_TIMEOUT: Duration = Duration::from_secs(1);
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Default)]
+struct CountingSpawner(AtomicUsize);
+
+impl Spawner for CountingSpawner {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        AmbientSpawner.spawn(task)
+    }
+}
+
+async fn thread_name(context: &mut Context<ReplyTo<Option<String>>>, _args: ()) -> Exit {
+    let reply_to = context.next_message().await;
+    let _ = reply_to.reply(std::thread::current().name().map(ToOwned::to_owned));
+    Exit::normal()
+}
+
+async fn wait_for_message(context: &mut Context<()>, _args: ()) -> Exit {
+    context.next_message().await;
+    Exit::normal()
+}
+
+#[test]
+fn actors_run_on_the_runtime_of_the_handle() {
+    let io_runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("io-runtime")
+        .enable_time()
+        .build()
+        .unwrap();
+    let spawner = Arc::new(io_runtime.handle().to_owned());
+
+    common::run(async move {
+        let system = System::new(SystemConfig { spawner, ..Default::default() });
+        let actor = system.spawn_typed(thread_name, (), Default::default()).await.unwrap();
+
+        let name = actor.call(|reply_to| reply_to, CALL_TIMEOUT).await.unwrap();
+        assert_eq!(name.as_deref(), Some("io-runtime"));
+        assert!(actor.wait().await.is_normal());
+    });
+
+    io_runtime.shutdown_background();
+}
+
+#[test]
+fn tasks_are_run_by_the_configured_spawner() {
+    common::run(async {
+        let spawner = Arc::new(CountingSpawner::default());
+        let system =
+            System::new(SystemConfig { spawner: spawner.to_owned(), ..Default::default() });
+
+        let actor = system.spawn(wait_for_message, (), Default::default()).await.unwrap();
+        assert_eq!(spawner.0.load(Ordering::SeqCst), 1);
+
+        system.send_after(actor, SMALL_DELAY, ()).await;
+        assert_eq!(spawner.0.load(Ordering::SeqCst), 2);
+
+        assert!(system.wait(actor).await.is_normal());
+    })
+}
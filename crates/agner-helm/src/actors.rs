@@ -5,8 +5,7 @@ use axum::response::IntoResponse;
 use axum::routing::{delete, get};
 use axum::{response, Extension, Json, Router};
 
-use agner_actors::{ActorID, Exit, System};
-use agner_sup::common::ParentActor;
+use agner_actors::{ActorID, Exit, ParentActor, System};
 
 use futures::StreamExt;
 
@@ -15,6 +15,9 @@ pub enum ExitSerde {
 
     #[serde(rename = "custom")]
     Custom(GenericError),
+
+    #[serde(rename = "panic")]
+    Panic { message: String, location: Option<String> },
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
@@ -66,6 +69,8 @@ impl From<ExitSerde> for Exit {
             ExitSerde::Backend(ge) => Self::Custom(BoxError::from(ge).into()),
             ExitSerde::Custom(ge) => Self::Custom(BoxError::from(ge).into()),
             ExitSerde::Standard(se) => Self::Standard(se.into()),
+            ExitSerde::Panic { message, location } =>
+                Self::Panic { message: message.into(), location: location.map(Into::into) },
         }
     }
 }
@@ -76,6 +81,10 @@ impl From<Exit> for ExitSerde {
             Exit::Standard(se) => Self::Standard(se.into()),
             Exit::Backend(be) => Self::Backend(GenericError::from_std_error(&be)),
             Exit::Custom(be) => Self::Custom(GenericError::from_std_error(&be)),
+            Exit::Panic { message, location } => Self::Panic {
+                message: message.to_string(),
+                location: location.as_deref().map(ToOwned::to_owned),
+            },
         }
     }
 }
@@ -0,0 +1,58 @@
+use agner_actors::{Context, Event, Exit};
+use agner_init_ack::ContextInitAckExt;
+
+use crate::server::{Message, Server};
+
+/// The behaviour function of a [`Server`].
+///
+/// It can be spawned via [`System::spawn`](agner_actors::System::spawn) directly, or started as a
+/// supervised child with either init-type: the init-ack is sent as soon as [`Server::init`]
+/// completes.
+pub async fn run<S>(context: &mut Context<Message<S>>, args: S::Args) -> Exit
+where
+    S: Server,
+{
+    let mut server = match S::init(context, args).await {
+        Ok(server) => {
+            context.init_ack_ok(None);
+            server
+        },
+        Err(reason) => {
+            tracing::trace!("[{}] init failed: {}", context.actor_id(), reason);
+            context.init_ack_err(reason.to_owned());
+            return reason
+        },
+    };
+
+    // a call that has stopped the server is left unanswered until the server exits: thus the
+    // caller gets the exit reason, rather than a dropped request.
+    let (reason, unanswered) = loop {
+        let (result, reply_to) = match context.next_event().await {
+            Event::Message(Message::Call(request, reply_to)) =>
+                match server.handle_call(context, request).await {
+                    Ok(reply) => {
+                        if reply_to.reply(reply).is_err() {
+                            tracing::trace!("[{}] the caller is gone", context.actor_id());
+                        }
+                        (Ok(()), None)
+                    },
+                    Err(reason) => (Err(reason), Some(reply_to)),
+                },
+            Event::Message(Message::Cast(request)) =>
+                (server.handle_cast(context, request).await, None),
+            Event::Signal(signal) => (server.handle_info(context, signal).await, None),
+        };
+
+        if let Err(reason) = result {
+            break (reason, reply_to)
+        }
+    };
+
+    tracing::trace!("[{}] terminating: {}", context.actor_id(), reason);
+    server.terminate(context, &reason).await;
+
+    if unanswered.is_some() {
+        match context.exit(reason).await {}
+    }
+    reason
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use agner_actors::system_error::CallError;
+use agner_actors::{ActorID, Exit, System};
+
+use crate::server::{Message, Server};
+
+/// A typed handle to a running [`Server`].
+pub struct Client<S> {
+    system: System,
+    actor_id: ActorID,
+    _server: PhantomData<fn(S)>,
+}
+
+impl<S: Server> Client<S> {
+    pub fn new(system: System, actor_id: ActorID) -> Self {
+        Self { system, actor_id, _server: Default::default() }
+    }
+
+    pub fn system(&self) -> &System {
+        &self.system
+    }
+
+    pub fn actor_id(&self) -> ActorID {
+        self.actor_id
+    }
+
+    /// Send a request to the server and wait for the reply (see
+    /// [`System::call`](agner_actors::System::call)).
+    pub async fn call(&self, request: S::Call, timeout: Duration) -> Result<S::Reply, CallError> {
+        self.system
+            .call(self.actor_id, |reply_to| Message::<S>::Call(request, reply_to), timeout)
+            .await
+    }
+
+    /// Send a request to the server without waiting for any reply.
+    pub async fn cast(&self, request: S::Cast) {
+        self.system.send(self.actor_id, Message::<S>::Cast(request)).await
+    }
+
+    /// Wait for the server to terminate.
+    pub async fn wait(&self) -> Exit {
+        self.system.wait(self.actor_id).await
+    }
+}
+
+impl<S> Clone for Client<S> {
+    fn clone(&self) -> Self {
+        Self {
+            system: self.system.to_owned(),
+            actor_id: self.actor_id,
+            _server: Default::default(),
+        }
+    }
+}
+
+impl<S> fmt::Debug for Client<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("server", &std::any::type_name::<S>())
+            .field("actor_id", &self.actor_id)
+            .finish()
+    }
+}
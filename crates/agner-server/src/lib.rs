@@ -0,0 +1,108 @@
+//! A generic server behaviour, akin to Erlang/OTP's `gen_server`.
+//!
+//! The implementor of the [`Server`] trait defines how the requests are handled, while the
+//! behaviour function [`run`] takes care of the dispatch, the replies and the init-ack.
+//!
+//! Example:
+//! ```
+//! use std::time::Duration;
+//! use agner_actors::{Context, Exit, System};
+//! use agner_server::{Client, Message, Server};
+//!
+//! struct Counter(usize);
+//!
+//! impl Server for Counter {
+//!     type Args = usize;
+//!     type Call = ();
+//!     type Reply = usize;
+//!     type Cast = usize;
+//!
+//!     async fn init(_context: &mut Context<Message<Self>>, initial: usize) -> Result<Self, Exit> {
+//!         Ok(Self(initial))
+//!     }
+//!
+//!     async fn handle_call(
+//!         &mut self,
+//!         _context: &mut Context<Message<Self>>,
+//!         _request: (),
+//!     ) -> Result<usize, Exit> {
+//!         Ok(self.0)
+//!     }
+//!
+//!     async fn handle_cast(
+//!         &mut self,
+//!         _context: &mut Context<Message<Self>>,
+//!         add: usize,
+//!     ) -> Result<(), Exit> {
+//!         self.0 += add;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let _ = async {
+//!     let system = System::new(Default::default());
+//!     let actor_id =
+//!         system.spawn(agner_server::run::<Counter>, 1, Default::default()).await.unwrap();
+//!
+//!     let counter = Client::<Counter>::new(system, actor_id);
+//!     counter.cast(2).await;
+//!     assert_eq!(counter.call((), Duration::from_secs(1)).await.unwrap(), 3);
+//! };
+//! ```
+//!
+//! # Typed clients
+//!
+//! There is no code generated per server: [`Client<S>`](Client) is typed by the server itself, so
+//! that [`Client::call`] only accepts the server's [`Call`](Server::Call) requests and returns its
+//! [`Reply`](Server::Reply), and [`Client::cast`] only accepts its [`Cast`](Server::Cast)
+//! requests. A derive- or macro-based generator would need a proc-macro crate, and would add
+//! nothing a server's API could not express as a few methods on a newtype around the client:
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use agner_actors::system_error::CallError;
+//! # use agner_actors::{Context, Exit};
+//! # use agner_server::{Client, Message, Server};
+//! # struct Counter(usize);
+//! # impl Server for Counter {
+//! #     type Args = usize;
+//! #     type Call = ();
+//! #     type Reply = usize;
+//! #     type Cast = usize;
+//! #     async fn init(_: &mut Context<Message<Self>>, initial: usize) -> Result<Self, Exit> {
+//! #         Ok(Self(initial))
+//! #     }
+//! #     async fn handle_call(&mut self, _: &mut Context<Message<Self>>, _: ()) -> Result<usize, Exit> {
+//! #         Ok(self.0)
+//! #     }
+//! #     async fn handle_cast(&mut self, _: &mut Context<Message<Self>>, add: usize) -> Result<(), Exit> {
+//! #         self.0 += add;
+//! #         Ok(())
+//! #     }
+//! # }
+//! #[derive(Debug, Clone)]
+//! struct CounterClient(Client<Counter>);
+//!
+//! impl CounterClient {
+//!     async fn add(&self, n: usize) {
+//!         self.0.cast(n).await
+//!     }
+//!     async fn get(&self) -> Result<usize, CallError> {
+//!         self.0.call((), Duration::from_secs(1)).await
+//!     }
+//! }
+//! ```
+//!
+//! The crate requires Rust 1.75: the callbacks of [`Server`] return `impl Future`.
+
+mod server;
+pub use server::{Message, Server};
+
+mod behaviour;
+pub use behaviour::run;
+
+mod client;
+pub use client::Client;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use std::fmt;
+use std::future::Future;
+
+use agner_actors::{Context, Exit, ReplyTo, Signal};
+
+/// The callbacks of a server.
+///
+/// The server's state is the implementing type itself: it is created by [`Server::init`] and
+/// then handed to the other callbacks.
+pub trait Server: Sized + Send + 'static {
+    /// The argument of the [`run`](crate::run) behaviour.
+    type Args: Send + 'static;
+
+    /// The requests handled by [`Server::handle_call`].
+    type Call: Send + Unpin + 'static;
+
+    /// The replies to the calls.
+    type Reply: Send + Unpin + 'static;
+
+    /// The requests handled by [`Server::handle_cast`].
+    type Cast: Send + Unpin + 'static;
+
+    /// Initialize the server's state.
+    ///
+    /// The init-ack is sent upon the completion of this callback: either positive or negative,
+    /// depending on the result.
+    fn init(
+        context: &mut Context<Message<Self>>,
+        args: Self::Args,
+    ) -> impl Future<Output = Result<Self, Exit>> + Send;
+
+    /// Handle a request that expects a reply.
+    ///
+    /// Returning an error terminates the server with that reason, the caller in this case
+    /// receives no reply.
+    fn handle_call(
+        &mut self,
+        context: &mut Context<Message<Self>>,
+        request: Self::Call,
+    ) -> impl Future<Output = Result<Self::Reply, Exit>> + Send;
+
+    /// Handle a request that expects no reply.
+    ///
+    /// Returning an error terminates the server with that reason.
+    fn handle_cast(
+        &mut self,
+        context: &mut Context<Message<Self>>,
+        request: Self::Cast,
+    ) -> impl Future<Output = Result<(), Exit>> + Send;
+
+    /// Handle a signal.
+    ///
+    /// Signals are only delivered to the servers that [trap
+    /// exits](agner_actors::Context::trap_exit) or [monitor](agner_actors::Context::monitor)
+    /// other actors. By default:
+    /// - an exit-signal sent via [`System::exit`](agner_actors::System::exit) terminates the server
+    ///   with the same reason;
+    /// - an abnormal exit of a linked actor terminates the server with
+    ///   [`Exit::linked`](agner_actors::Exit::linked);
+    /// - [`Signal::Down`] is ignored.
+    fn handle_info(
+        &mut self,
+        context: &mut Context<Message<Self>>,
+        signal: Signal,
+    ) -> impl Future<Output = Result<(), Exit>> + Send {
+        async move {
+            match signal {
+                Signal::Exit(from, reason) if from == context.actor_id() => Err(reason),
+                Signal::Exit(from, reason) if reason.is_normal() => {
+                    tracing::trace!(
+                        "[{}] linked actor {} exited normally",
+                        context.actor_id(),
+                        from
+                    );
+                    Ok(())
+                },
+                Signal::Exit(from, reason) => Err(Exit::linked(from, reason)),
+                Signal::Down(..) => Ok(()),
+            }
+        }
+    }
+
+    /// Invoked before the server terminates because of a callback returning an error.
+    fn terminate(
+        &mut self,
+        _context: &mut Context<Message<Self>>,
+        _reason: &Exit,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
+/// The message accepted by the [server behaviour](crate::run).
+pub enum Message<S: Server> {
+    Call(S::Call, ReplyTo<S::Reply>),
+    Cast(S::Cast),
+}
+
+impl<S: Server> fmt::Debug for Message<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Call(..) => write!(f, "Call<{}>", std::any::type_name::<S::Call>()),
+            Self::Cast(..) => write!(f, "Cast<{}>", std::any::type_name::<S::Cast>()),
+        }
+    }
+}
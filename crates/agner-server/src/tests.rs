@@ -0,0 +1,225 @@
+use std::time::Duration;
+
+use agner_actors::system_error::CallError;
+use agner_actors::{ActorID, Context, Exit, Never, Signal, System};
+use agner_init_ack::ContextInitAckExt;
+use agner_sup::common::{CreateChild, GenChildSpec, StartChildError, WithAck};
+use tokio::sync::mpsc;
+
+use crate::{Client, Message, Server};
+
+const CALL_TIMEOUT: Duration = Duration::from_secs(1);
+const SMALL_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+enum Call {
+    Get,
+    Stop,
+}
+
+#[derive(Debug)]
+enum Cast {
+    Add(usize),
+    Crash,
+}
+
+struct Counter {
+    value: usize,
+    terminated_tx: mpsc::UnboundedSender<Exit>,
+}
+
+#[derive(Clone)]
+struct CounterArgs {
+    initial: Option<usize>,
+    trap_exit: bool,
+    terminated_tx: mpsc::UnboundedSender<Exit>,
+}
+
+impl Server for Counter {
+    type Args = CounterArgs;
+    type Call = Call;
+    type Reply = usize;
+    type Cast = Cast;
+
+    async fn init(context: &mut Context<Message<Self>>, args: CounterArgs) -> Result<Self, Exit> {
+        let CounterArgs { initial, trap_exit, terminated_tx } = args;
+        let value = initial.ok_or_else(|| Exit::from_message("no initial value"))?;
+        context.trap_exit(trap_exit).await;
+        Ok(Self { value, terminated_tx })
+    }
+
+    async fn handle_call(
+        &mut self,
+        _context: &mut Context<Message<Self>>,
+        request: Call,
+    ) -> Result<usize, Exit> {
+        match request {
+            Call::Get => Ok(self.value),
+            Call::Stop => Err(Exit::normal()),
+        }
+    }
+
+    async fn handle_cast(
+        &mut self,
+        _context: &mut Context<Message<Self>>,
+        request: Cast,
+    ) -> Result<(), Exit> {
+        match request {
+            Cast::Add(n) => self.value += n,
+            Cast::Crash => return Err(Exit::from_message("crash")),
+        }
+        Ok(())
+    }
+
+    async fn terminate(&mut self, _context: &mut Context<Message<Self>>, reason: &Exit) {
+        let _ = self.terminated_tx.send(reason.to_owned());
+    }
+}
+
+fn counter_args(
+    initial: Option<usize>,
+    trap_exit: bool,
+) -> (CounterArgs, mpsc::UnboundedReceiver<Exit>) {
+    let (terminated_tx, terminated_rx) = mpsc::unbounded_channel();
+    (CounterArgs { initial, trap_exit, terminated_tx }, terminated_rx)
+}
+
+async fn sup(context: &mut Context<Never>, (): ()) {
+    context.init_ack_ok(Default::default());
+    std::future::pending().await
+}
+
+#[tokio::test]
+async fn call_and_cast() {
+    let system = System::new(Default::default());
+    let (args, mut terminated_rx) = counter_args(Some(1), false);
+    let actor_id = system.spawn(crate::run::<Counter>, args, Default::default()).await.unwrap();
+    let counter = Client::<Counter>::new(system.to_owned(), actor_id);
+
+    assert_eq!(counter.call(Call::Get, CALL_TIMEOUT).await.unwrap(), 1);
+    counter.cast(Cast::Add(2)).await;
+    counter.cast(Cast::Add(3)).await;
+    assert_eq!(counter.call(Call::Get, CALL_TIMEOUT).await.unwrap(), 6);
+
+    let err = counter.call(Call::Stop, CALL_TIMEOUT).await.unwrap_err();
+    assert!(matches!(err, CallError::CalleeExited(reason) if reason.is_normal()));
+    assert!(counter.wait().await.is_normal());
+    assert!(terminated_rx.recv().await.unwrap().is_normal());
+}
+
+#[tokio::test]
+async fn failing_cast_terminates_the_server() {
+    let system = System::new(Default::default());
+    let (args, mut terminated_rx) = counter_args(Some(1), false);
+    let actor_id = system.spawn(crate::run::<Counter>, args, Default::default()).await.unwrap();
+    let counter = Client::<Counter>::new(system.to_owned(), actor_id);
+
+    counter.cast(Cast::Crash).await;
+    assert!(counter.wait().await.is_custom());
+    assert!(terminated_rx.recv().await.unwrap().is_custom());
+}
+
+#[tokio::test]
+async fn exit_signal_is_handled_by_handle_info() {
+    let system = System::new(Default::default());
+    let (args, mut terminated_rx) = counter_args(Some(1), true);
+    let actor_id = system.spawn(crate::run::<Counter>, args, Default::default()).await.unwrap();
+    let counter = Client::<Counter>::new(system.to_owned(), actor_id);
+    assert_eq!(counter.call(Call::Get, CALL_TIMEOUT).await.unwrap(), 1);
+
+    system.exit(actor_id, Exit::shutdown()).await;
+    assert!(counter.wait().await.is_shutdown());
+    assert!(terminated_rx.recv().await.unwrap().is_shutdown());
+}
+
+#[tokio::test]
+async fn custom_handle_info() {
+    struct Watcher(mpsc::UnboundedSender<(ActorID, Exit)>);
+
+    impl Server for Watcher {
+        type Args = (ActorID, mpsc::UnboundedSender<(ActorID, Exit)>);
+        type Call = Never;
+        type Reply = Never;
+        type Cast = Never;
+
+        async fn init(
+            context: &mut Context<Message<Self>>,
+            (watched, downs_tx): Self::Args,
+        ) -> Result<Self, Exit> {
+            context.monitor(watched).await;
+            Ok(Self(downs_tx))
+        }
+
+        async fn handle_call(
+            &mut self,
+            _context: &mut Context<Message<Self>>,
+            request: Never,
+        ) -> Result<Never, Exit> {
+            match request {}
+        }
+
+        async fn handle_cast(
+            &mut self,
+            _context: &mut Context<Message<Self>>,
+            request: Never,
+        ) -> Result<(), Exit> {
+            match request {}
+        }
+
+        async fn handle_info(
+            &mut self,
+            _context: &mut Context<Message<Self>>,
+            signal: Signal,
+        ) -> Result<(), Exit> {
+            if let Signal::Down(_, actor_id, reason) = signal {
+                let _ = self.0.send((actor_id, reason));
+            }
+            Ok(())
+        }
+    }
+
+    let system = System::new(Default::default());
+    let watched = system.spawn(sup, (), Default::default()).await.unwrap();
+    let (downs_tx, mut downs_rx) = mpsc::unbounded_channel();
+    let _watcher = system
+        .spawn(crate::run::<Watcher>, (watched, downs_tx), Default::default())
+        .await
+        .unwrap();
+    tokio::time::sleep(SMALL_DELAY).await;
+
+    system.exit(watched, Exit::shutdown()).await;
+    let (actor_id, reason) = downs_rx.recv().await.unwrap();
+    assert_eq!(actor_id, watched);
+    assert!(reason.is_shutdown());
+}
+
+#[tokio::test]
+async fn start_as_a_child_with_ack() {
+    let system = System::new(Default::default());
+    let sup_id = system.spawn(sup, (), Default::default()).await.unwrap();
+
+    let (args, _terminated_rx) = counter_args(Some(10), false);
+    let mut child_spec = GenChildSpec::new()
+        .behaviour(crate::run::<Counter>)
+        .args_clone(args)
+        .init_type(WithAck::default());
+
+    let actor_id = child_spec.create_child(&system, sup_id, ()).await.unwrap();
+    let counter = Client::<Counter>::new(system.to_owned(), actor_id);
+    assert_eq!(counter.call(Call::Get, CALL_TIMEOUT).await.unwrap(), 10);
+}
+
+#[tokio::test]
+async fn failed_init_is_reported_via_init_ack() {
+    let system = System::new(Default::default());
+    let sup_id = system.spawn(sup, (), Default::default()).await.unwrap();
+
+    let (args, _terminated_rx) = counter_args(None, false);
+    let mut child_spec = GenChildSpec::new()
+        .behaviour(crate::run::<Counter>)
+        .args_clone(args)
+        .init_type(WithAck::default());
+
+    let err = child_spec.create_child(&system, sup_id, ()).await.unwrap_err();
+    assert!(matches!(err, StartChildError::InitAckFailure(reason) if reason.is_custom()));
+}
@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+
+use agner_actors::{Context, Exit};
+use agner_init_ack::ContextInitAckExt;
+use tokio::time::Instant;
+
+use crate::state_machine::{Input, StateMachine};
+use crate::transition::{StateEnter, Target, Transition};
+
+/// The behaviour function of a [`StateMachine`].
+///
+/// It can be spawned via [`System::spawn`](agner_actors::System::spawn) directly, or started as a
+/// supervised child with either init-type: the init-ack is sent as soon as
+/// [`StateMachine::init`] completes.
+pub async fn run<SM>(context: &mut Context<SM::Event>, args: SM::Args) -> Exit
+where
+    SM: StateMachine,
+{
+    let (mut machine, mut state) = match SM::init(context, args).await {
+        Ok(initialized) => {
+            context.init_ack_ok(None);
+            initialized
+        },
+        Err(reason) => {
+            tracing::trace!("[{}] init failed: {}", context.actor_id(), reason);
+            context.init_ack_err(reason.to_owned());
+            return reason
+        },
+    };
+
+    let mut timeouts = Timeouts::default();
+    let mut replayed = VecDeque::<Input<SM::Event>>::new();
+    let mut postponed = VecDeque::<Input<SM::Event>>::new();
+
+    let state_enter = machine.state_enter(context, &state, &state).await;
+    let mut stop = timeouts.on_state_enter(state_enter);
+
+    let reason = loop {
+        if let Some(reason) = stop.take() {
+            break reason
+        }
+
+        let input = if let Some(input) = replayed.pop_front() {
+            input
+        } else {
+            timeouts.next_input(context).await
+        };
+        timeouts.event = None;
+
+        tracing::trace!("[{}] {:?} in {:?}", context.actor_id(), input, state);
+        let Transition { target, postponed: postpone, state_timeout, event_timeout } =
+            machine.handle_event(context, &state, input).await;
+
+        postponed.extend(postpone);
+        timeouts.event = event_timeout.map(|timeout| Instant::now() + timeout);
+
+        match target {
+            Target::Stop(reason) => break reason,
+            Target::Keep => (),
+            Target::Next(next) if next == state => (),
+            Target::Next(next) => {
+                tracing::trace!("[{}] {:?} -> {:?}", context.actor_id(), state, next);
+                let old_state = std::mem::replace(&mut state, next);
+
+                timeouts.state = None;
+                // the postponed events have arrived before those still waiting to be replayed
+                for input in postponed.drain(..).rev() {
+                    replayed.push_front(input);
+                }
+
+                let state_enter = machine.state_enter(context, &old_state, &state).await;
+                stop = timeouts.on_state_enter(state_enter);
+            },
+        }
+        if let Some(timeout) = state_timeout {
+            timeouts.state = Some(Instant::now() + timeout);
+        }
+    };
+
+    tracing::trace!("[{}] terminating in {:?}: {}", context.actor_id(), state, reason);
+    machine.terminate(context, &state, &reason).await;
+
+    reason
+}
+
+#[derive(Debug, Default)]
+struct Timeouts {
+    state: Option<Instant>,
+    event: Option<Instant>,
+}
+
+impl Timeouts {
+    fn on_state_enter(&mut self, state_enter: StateEnter) -> Option<Exit> {
+        let StateEnter { stop, state_timeout } = state_enter;
+        if let Some(timeout) = state_timeout {
+            self.state = Some(Instant::now() + timeout);
+        }
+        stop
+    }
+
+    async fn next_input<E>(&mut self, context: &mut Context<E>) -> Input<E>
+    where
+        E: Unpin,
+    {
+        let state_timeout = async {
+            if let Some(deadline) = self.state {
+                tokio::time::sleep_until(deadline).await
+            } else {
+                std::future::pending().await
+            }
+        };
+        let event_timeout = async {
+            if let Some(deadline) = self.event {
+                tokio::time::sleep_until(deadline).await
+            } else {
+                std::future::pending().await
+            }
+        };
+
+        let input = tokio::select! {
+            biased;
+
+            event = context.next_event() => event.into(),
+            () = state_timeout => Input::StateTimeout,
+            () = event_timeout => Input::EventTimeout,
+        };
+        if matches!(input, Input::StateTimeout) {
+            self.state = None;
+        }
+        input
+    }
+}
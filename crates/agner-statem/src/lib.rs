@@ -0,0 +1,23 @@
+//! A generic state machine behaviour, akin to Erlang/OTP's `gen_statem`.
+//!
+//! The implementor of the [`StateMachine`] trait handles the [inputs](Input) depending on the
+//! current state and decides upon the [transitions](Transition), while the behaviour function
+//! [`run`] takes care of:
+//! - invoking [`StateMachine::state_enter`] upon each state change;
+//! - keeping the postponed inputs and re-delivering them upon a state change;
+//! - the state-timeouts (cancelled by a state change) and the event-timeouts (cancelled by any
+//!   input).
+//!
+//! The crate requires Rust 1.75: the callbacks of [`StateMachine`] return `impl Future`.
+
+mod state_machine;
+pub use state_machine::{Input, StateMachine};
+
+mod transition;
+pub use transition::{StateEnter, Transition};
+
+mod behaviour;
+pub use behaviour::run;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,93 @@
+use std::fmt;
+use std::future::Future;
+
+use agner_actors::{Context, Exit, Signal};
+
+use crate::transition::{StateEnter, Transition};
+
+/// An input handled by a [`StateMachine`].
+pub enum Input<E> {
+    /// A message received by the actor.
+    Message(E),
+
+    /// A signal received by the actor.
+    Signal(Signal),
+
+    /// The [state-timeout](Transition::state_timeout) has expired.
+    StateTimeout,
+
+    /// The [event-timeout](Transition::event_timeout) has expired.
+    EventTimeout,
+}
+
+/// The callbacks of a state machine.
+pub trait StateMachine: Sized + Send + 'static {
+    /// The argument of the [`run`](crate::run) behaviour.
+    type Args: Send + 'static;
+
+    /// The state of the machine.
+    ///
+    /// The transition into a state equal to the current one is not considered a state change.
+    type State: PartialEq + fmt::Debug + Send + Sync + 'static;
+
+    /// The messages accepted by the machine.
+    type Event: Send + Unpin + 'static;
+
+    /// Initialize the machine, returning its data along with the initial state.
+    ///
+    /// The init-ack is sent upon the completion of this callback: either positive or negative,
+    /// depending on the result.
+    fn init(
+        context: &mut Context<Self::Event>,
+        args: Self::Args,
+    ) -> impl Future<Output = Result<(Self, Self::State), Exit>> + Send;
+
+    /// Handle an input in the current state.
+    fn handle_event(
+        &mut self,
+        context: &mut Context<Self::Event>,
+        state: &Self::State,
+        input: Input<Self::Event>,
+    ) -> impl Future<Output = Transition<Self::State, Self::Event>> + Send;
+
+    /// Invoked upon entering a state (including the initial one, in which case `old_state` is
+    /// the same as `state`).
+    fn state_enter(
+        &mut self,
+        _context: &mut Context<Self::Event>,
+        _old_state: &Self::State,
+        _state: &Self::State,
+    ) -> impl Future<Output = StateEnter> + Send {
+        async { StateEnter::ok() }
+    }
+
+    /// Invoked before the machine terminates because of a [stop-transition](Transition::stop).
+    fn terminate(
+        &mut self,
+        _context: &mut Context<Self::Event>,
+        _state: &Self::State,
+        _reason: &Exit,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
+impl<E> From<agner_actors::Event<E>> for Input<E> {
+    fn from(event: agner_actors::Event<E>) -> Self {
+        match event {
+            agner_actors::Event::Message(message) => Self::Message(message),
+            agner_actors::Event::Signal(signal) => Self::Signal(signal),
+        }
+    }
+}
+
+impl<E> fmt::Debug for Input<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Message(_) => write!(f, "Message<{}>", std::any::type_name::<E>()),
+            Self::Signal(signal) => f.debug_tuple("Signal").field(signal).finish(),
+            Self::StateTimeout => write!(f, "StateTimeout"),
+            Self::EventTimeout => write!(f, "EventTimeout"),
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use std::time::Duration;
+
+use agner_actors::{ActorID, Context, Exit, System};
+use agner_sup::common::WithAck;
+use agner_sup::mixed::{self, MixedChildSpec, OneForOne, RestartIntensity};
+use agner_sup::uniform::{self, UniformChildSpec};
+use tokio::sync::mpsc;
+
+use crate::{Input, StateEnter, StateMachine, Transition};
+
+const LONG_TIMEOUT: Duration = Duration::from_secs(10);
+const SHORT_TIMEOUT: Duration = Duration::from_millis(100);
+const SMALL_DELAY: Duration = Duration::from_millis(300);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Idle,
+    Connecting,
+    Connected,
+}
+
+#[derive(Debug)]
+enum Event {
+    Connect,
+    Ack,
+    Data(usize),
+    Crash,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Report {
+    Enter(State),
+    Data(usize),
+    Terminate,
+}
+
+#[derive(Debug, Clone)]
+struct Args {
+    connect_timeout: Duration,
+    idle_timeout: Duration,
+    report_tx: mpsc::UnboundedSender<Report>,
+}
+
+struct Connection(Args);
+
+impl StateMachine for Connection {
+    type Args = Args;
+    type State = State;
+    type Event = Event;
+
+    async fn init(_context: &mut Context<Event>, args: Args) -> Result<(Self, State), Exit> {
+        Ok((Self(args), State::Idle))
+    }
+
+    async fn handle_event(
+        &mut self,
+        _context: &mut Context<Event>,
+        state: &State,
+        input: Input<Event>,
+    ) -> Transition<State, Event> {
+        match (state, input) {
+            (_, Input::Message(Event::Crash)) => Transition::stop(Exit::from_message("crash")),
+
+            (State::Idle, Input::Message(Event::Connect)) => Transition::next(State::Connecting),
+
+            (State::Connecting, Input::Message(Event::Ack)) =>
+                Transition::next(State::Connected).event_timeout(self.0.idle_timeout),
+            (State::Connecting, Input::StateTimeout) => Transition::next(State::Idle),
+            (State::Connecting, input @ Input::Message(Event::Data(_))) =>
+                Transition::keep().postpone(input),
+
+            (State::Connected, Input::Message(Event::Data(n))) => {
+                let _ = self.0.report_tx.send(Report::Data(n));
+                Transition::keep().event_timeout(self.0.idle_timeout)
+            },
+            (State::Connected, Input::EventTimeout) => Transition::next(State::Idle),
+
+            (_, _) => Transition::keep(),
+        }
+    }
+
+    async fn state_enter(
+        &mut self,
+        _context: &mut Context<Event>,
+        _old_state: &State,
+        state: &State,
+    ) -> StateEnter {
+        let _ = self.0.report_tx.send(Report::Enter(*state));
+        match state {
+            State::Connecting => StateEnter::ok().state_timeout(self.0.connect_timeout),
+            _ => StateEnter::ok(),
+        }
+    }
+
+    async fn terminate(&mut self, _context: &mut Context<Event>, _state: &State, _reason: &Exit) {
+        let _ = self.0.report_tx.send(Report::Terminate);
+    }
+}
+
+fn args(
+    connect_timeout: Duration,
+    idle_timeout: Duration,
+) -> (Args, mpsc::UnboundedReceiver<Report>) {
+    let (report_tx, report_rx) = mpsc::unbounded_channel();
+    (Args { connect_timeout, idle_timeout, report_tx }, report_rx)
+}
+
+async fn expect(report_rx: &mut mpsc::UnboundedReceiver<Report>, expected: Report) {
+    let actual = tokio::time::timeout(LONG_TIMEOUT, report_rx.recv()).await.unwrap().unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn state_timeout_and_state_enter() {
+    let system = System::new(Default::default());
+    let (args, mut report_rx) = args(SHORT_TIMEOUT, LONG_TIMEOUT);
+    let machine = system.spawn(crate::run::<Connection>, args, Default::default()).await.unwrap();
+
+    expect(&mut report_rx, Report::Enter(State::Idle)).await;
+
+    system.send(machine, Event::Connect).await;
+    expect(&mut report_rx, Report::Enter(State::Connecting)).await;
+    expect(&mut report_rx, Report::Enter(State::Idle)).await;
+
+    system.send(machine, Event::Connect).await;
+    system.send(machine, Event::Ack).await;
+    expect(&mut report_rx, Report::Enter(State::Connecting)).await;
+    expect(&mut report_rx, Report::Enter(State::Connected)).await;
+
+    tokio::time::sleep(SMALL_DELAY).await;
+    assert!(report_rx.try_recv().is_err(), "state-timeout should be cancelled by state change");
+
+    system.send(machine, Event::Crash).await;
+    expect(&mut report_rx, Report::Terminate).await;
+    assert!(system.wait(machine).await.is_custom());
+}
+
+#[tokio::test]
+async fn postponed_events_are_replayed_upon_state_change() {
+    let system = System::new(Default::default());
+    let (args, mut report_rx) = args(LONG_TIMEOUT, LONG_TIMEOUT);
+    let machine = system.spawn(crate::run::<Connection>, args, Default::default()).await.unwrap();
+
+    expect(&mut report_rx, Report::Enter(State::Idle)).await;
+
+    system.send(machine, Event::Connect).await;
+    for n in 0..3 {
+        system.send(machine, Event::Data(n)).await;
+    }
+    expect(&mut report_rx, Report::Enter(State::Connecting)).await;
+
+    tokio::time::sleep(SMALL_DELAY).await;
+    assert!(report_rx.try_recv().is_err(), "data should be postponed while connecting");
+
+    system.send(machine, Event::Ack).await;
+    system.send(machine, Event::Data(3)).await;
+
+    expect(&mut report_rx, Report::Enter(State::Connected)).await;
+    for n in 0..4 {
+        expect(&mut report_rx, Report::Data(n)).await;
+    }
+}
+
+/// Postpones the items until opened; then the first replayed item completes the opening, being
+/// postponed again.
+struct Gate(mpsc::UnboundedSender<usize>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GateState {
+    Closed,
+    Opening,
+    Open,
+}
+
+#[derive(Debug)]
+enum GateEvent {
+    Open,
+    Item(usize),
+}
+
+impl StateMachine for Gate {
+    type Args = mpsc::UnboundedSender<usize>;
+    type State = GateState;
+    type Event = GateEvent;
+
+    async fn init(
+        _context: &mut Context<GateEvent>,
+        items_tx: Self::Args,
+    ) -> Result<(Self, GateState), Exit> {
+        Ok((Self(items_tx), GateState::Closed))
+    }
+
+    async fn handle_event(
+        &mut self,
+        _context: &mut Context<GateEvent>,
+        state: &GateState,
+        input: Input<GateEvent>,
+    ) -> Transition<GateState, GateEvent> {
+        match (state, input) {
+            (GateState::Closed, Input::Message(GateEvent::Open)) =>
+                Transition::next(GateState::Opening),
+            (GateState::Closed, input @ Input::Message(GateEvent::Item(_))) =>
+                Transition::keep().postpone(input),
+            (GateState::Opening, input @ Input::Message(GateEvent::Item(_))) =>
+                Transition::next(GateState::Open).postpone(input),
+            (GateState::Open, Input::Message(GateEvent::Item(n))) => {
+                let _ = self.0.send(n);
+                Transition::keep()
+            },
+            (_, _) => Transition::keep(),
+        }
+    }
+}
+
+#[tokio::test]
+async fn events_postponed_during_replay_keep_their_order() {
+    let system = System::new(Default::default());
+    let (items_tx, mut items_rx) = mpsc::unbounded_channel();
+    let gate = system.spawn(crate::run::<Gate>, items_tx, Default::default()).await.unwrap();
+
+    for n in 0..3 {
+        system.send(gate, GateEvent::Item(n)).await;
+    }
+    system.send(gate, GateEvent::Open).await;
+
+    for n in 0..3 {
+        let item = tokio::time::timeout(LONG_TIMEOUT, items_rx.recv()).await.unwrap().unwrap();
+        assert_eq!(item, n);
+    }
+}
+
+#[tokio::test]
+async fn event_timeout_is_cancelled_by_any_input() {
+    let system = System::new(Default::default());
+    let (args, mut report_rx) = args(LONG_TIMEOUT, SMALL_DELAY);
+    let machine = system.spawn(crate::run::<Connection>, args, Default::default()).await.unwrap();
+
+    system.send(machine, Event::Connect).await;
+    system.send(machine, Event::Ack).await;
+    expect(&mut report_rx, Report::Enter(State::Idle)).await;
+    expect(&mut report_rx, Report::Enter(State::Connecting)).await;
+    expect(&mut report_rx, Report::Enter(State::Connected)).await;
+
+    for n in 0..5 {
+        tokio::time::sleep(SMALL_DELAY / 3).await;
+        system.send(machine, Event::Data(n)).await;
+        expect(&mut report_rx, Report::Data(n)).await;
+    }
+
+    expect(&mut report_rx, Report::Enter(State::Idle)).await;
+}
+
+#[tokio::test]
+async fn runs_under_mixed_supervisor() {
+    let system = System::new(Default::default());
+    let (args, mut report_rx) = args(LONG_TIMEOUT, LONG_TIMEOUT);
+
+    let restart_strategy = OneForOne::new(RestartIntensity::new(1, Duration::from_secs(60)));
+    let sup_spec = mixed::SupSpec::new(restart_strategy).with_child(
+        MixedChildSpec::mixed("connection")
+            .behaviour(crate::run::<Connection>)
+            .args_clone(args)
+            .init_type(WithAck::new()),
+    );
+    let sup_id = system.spawn(mixed::run, sup_spec, Default::default()).await.unwrap();
+    expect(&mut report_rx, Report::Enter(State::Idle)).await;
+
+    let [(_, machine_1)]: [(&'static str, ActorID); 1] =
+        mixed::which_children(&system, sup_id).await.unwrap().try_into().unwrap();
+
+    system.send(machine_1, Event::Crash).await;
+    expect(&mut report_rx, Report::Terminate).await;
+    expect(&mut report_rx, Report::Enter(State::Idle)).await;
+
+    let [(_, machine_2)]: [(&'static str, ActorID); 1] =
+        mixed::which_children(&system, sup_id).await.unwrap().try_into().unwrap();
+    assert_ne!(machine_1, machine_2);
+}
+
+#[tokio::test]
+async fn runs_under_uniform_supervisor() {
+    let system = System::new(Default::default());
+    let (args, mut report_rx) = args(LONG_TIMEOUT, LONG_TIMEOUT);
+
+    let sup_spec = uniform::SupSpec::new(
+        UniformChildSpec::uniform()
+            .behaviour(crate::run::<Connection>)
+            .args_call1(move |()| args.to_owned())
+            .init_type(WithAck::new()),
+    );
+    let sup_id = system.spawn(uniform::run, sup_spec, Default::default()).await.unwrap();
+
+    let machine = uniform::start_child(&system, sup_id, ()).await.unwrap();
+    expect(&mut report_rx, Report::Enter(State::Idle)).await;
+
+    uniform::stop_child::<()>(&system, sup_id, machine).await.unwrap();
+    assert!(system.wait(machine).await.is_shutdown());
+}
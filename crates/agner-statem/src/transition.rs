@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use agner_actors::Exit;
+
+use crate::state_machine::Input;
+
+/// The outcome of [`StateMachine::handle_event`](crate::StateMachine::handle_event).
+#[derive(Debug)]
+pub struct Transition<S, E> {
+    pub(crate) target: Target<S>,
+    pub(crate) postponed: Option<Input<E>>,
+    pub(crate) state_timeout: Option<Duration>,
+    pub(crate) event_timeout: Option<Duration>,
+}
+
+/// The outcome of [`StateMachine::state_enter`](crate::StateMachine::state_enter).
+#[derive(Debug)]
+pub struct StateEnter {
+    pub(crate) stop: Option<Exit>,
+    pub(crate) state_timeout: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub(crate) enum Target<S> {
+    Keep,
+    Next(S),
+    Stop(Exit),
+}
+
+impl<S, E> Transition<S, E> {
+    /// Stay in the current state.
+    pub fn keep() -> Self {
+        Self::with_target(Target::Keep)
+    }
+
+    /// Move to the `next` state.
+    pub fn next(next: S) -> Self {
+        Self::with_target(Target::Next(next))
+    }
+
+    /// Terminate the machine with the specified `reason`.
+    pub fn stop(reason: Exit) -> Self {
+        Self::with_target(Target::Stop(reason))
+    }
+
+    /// Put the `input` aside until the next state change.
+    pub fn postpone(self, input: Input<E>) -> Self {
+        Self { postponed: Some(input), ..self }
+    }
+
+    /// Deliver [`Input::StateTimeout`] after `timeout`, unless the state changes earlier.
+    pub fn state_timeout(self, timeout: Duration) -> Self {
+        Self { state_timeout: Some(timeout), ..self }
+    }
+
+    /// Deliver [`Input::EventTimeout`] after `timeout`, unless some input arrives earlier.
+    pub fn event_timeout(self, timeout: Duration) -> Self {
+        Self { event_timeout: Some(timeout), ..self }
+    }
+
+    fn with_target(target: Target<S>) -> Self {
+        Self { target, postponed: None, state_timeout: None, event_timeout: None }
+    }
+}
+
+impl StateEnter {
+    /// Proceed in the entered state.
+    pub fn ok() -> Self {
+        Self { stop: None, state_timeout: None }
+    }
+
+    /// Terminate the machine with the specified `reason`.
+    pub fn stop(reason: Exit) -> Self {
+        Self { stop: Some(reason), state_timeout: None }
+    }
+
+    /// Deliver [`Input::StateTimeout`] after `timeout`, unless the state changes earlier.
+    pub fn state_timeout(self, timeout: Duration) -> Self {
+        Self { state_timeout: Some(timeout), ..self }
+    }
+}
@@ -4,13 +4,10 @@
 use std::future::Future;
 use std::pin::Pin;
 
-use agner_actors::ActorID;
-
 pub type BoxedFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 pub type StaticBoxedFuture<T> = BoxedFuture<'static, T>;
 
-#[derive(Debug, Clone, Copy)]
-pub struct ParentActor(pub ActorID);
+pub use agner_actors::ParentActor;
 
 mod start_child;
 pub use start_child::{start_child, StartChildError};
@@ -8,6 +8,9 @@ mod restart_strategy;
 mod sup_spec;
 mod supervisor;
 
+#[cfg(test)]
+mod tests;
+
 use agner_actors::{ActorID, Exit, System};
 use agner_utils::result_err_flatten::ResultErrFlattenIn;
 pub use child_id::ChildID;
@@ -5,7 +5,6 @@ use std::time::{Duration, Instant};
 
 use agner_actors::{ActorID, Context, Event, Exit, Never, Signal};
 use agner_init_ack::ContextInitAckExt;
-use agner_utils::future_timeout_ext::FutureTimeoutExt;
 use agner_utils::std_error_pp::StdErrorPP;
 
 use tokio::sync::oneshot;
@@ -65,7 +64,7 @@ where
             );
 
             let next_event_opt = if decider_has_actions || !first_context_poll {
-                context.next_event().timeout(Duration::ZERO).await.ok()
+                context.next_event_timeout(Duration::ZERO).await.event()
             } else {
                 Some(context.next_event().await)
             };
@@ -126,6 +125,15 @@ where
                 .map_err(Exit::custom)?;
             Ok(())
         },
+        Signal::Down(monitor_ref, actor_id, exit_reason) => {
+            tracing::trace!(
+                "unexpected down-signal [ref: {}, actor: {}, exit: {}]. Ignoring.",
+                monitor_ref,
+                actor_id,
+                exit_reason.pp()
+            );
+            Ok(())
+        },
     }
 }
 
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use agner_actors::{ActorID, Blocking, BlockingContext, Context, Exit, Never, System};
+use tokio::sync::mpsc;
+
+use crate::mixed::{self, ChildType, MixedChildSpec, OneForOne, RestartIntensity, SupSpec};
+
+#[tokio::test]
+async fn panicking_child_is_restarted() {
+    async fn worker(
+        context: &mut Context<&'static str>,
+        started_tx: mpsc::UnboundedSender<ActorID>,
+    ) -> Never {
+        let _ = started_tx.send(context.actor_id());
+        let message = context.next_message().await;
+        panic!("{}", message)
+    }
+
+    let (started_tx, mut started_rx) = mpsc::unbounded_channel();
+    let restart_intensity = RestartIntensity::new(1, Duration::from_secs(60));
+    let sup_spec = SupSpec::new(OneForOne::new(restart_intensity)).with_child(
+        MixedChildSpec::mixed("worker")
+            .behaviour(worker)
+            .args_clone(started_tx)
+            .child_type(ChildType::Transient),
+    );
+
+    let system = System::new(Default::default());
+    let sup = system.spawn(mixed::run, sup_spec, Default::default()).await.unwrap();
+
+    let w1 = started_rx.recv().await.unwrap();
+    system.send(w1, "first").await;
+    let w1_exited = system.wait(w1).await;
+    assert!(w1_exited.is_panic(), "{:?}", w1_exited);
+
+    let w2 = started_rx.recv().await.unwrap();
+    assert_ne!(w1, w2);
+    system.send(w2, "second").await;
+
+    let sup_exited = system.wait(sup).await;
+    assert!(sup_exited.is_shutdown(), "{:?}", sup_exited);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blocking_child_is_restarted() {
+    fn worker(
+        context: &mut BlockingContext<&'static str>,
+        started_tx: mpsc::UnboundedSender<ActorID>,
+    ) -> Exit {
+        let _ = started_tx.send(context.actor_id());
+        match context.recv_blocking() {
+            Some(message) => Exit::from_message(message),
+            None => Exit::normal(),
+        }
+    }
+
+    let (started_tx, mut started_rx) = mpsc::unbounded_channel();
+    let restart_intensity = RestartIntensity::new(1, Duration::from_secs(60));
+    let sup_spec = SupSpec::new(OneForOne::new(restart_intensity)).with_child(
+        MixedChildSpec::mixed("worker")
+            .behaviour(Blocking(worker))
+            .args_clone(started_tx)
+            .child_type(ChildType::Permanent),
+    );
+
+    let system = System::new(Default::default());
+    let sup = system.spawn(mixed::run, sup_spec, Default::default()).await.unwrap();
+
+    let w1 = started_rx.recv().await.unwrap();
+    system.send(w1, "failure").await;
+    let w1_exited = system.wait(w1).await;
+    assert!(w1_exited.is_custom(), "{:?}", w1_exited);
+
+    let w2 = started_rx.recv().await.unwrap();
+    assert_ne!(w1, w2);
+
+    system.exit(sup, Exit::shutdown()).await;
+    assert!(system.wait(sup).await.is_shutdown());
+    assert!(system.wait(w2).await.is_shutdown());
+}
@@ -174,10 +174,34 @@ where
                     context.exit(Exit::linked(actor_id, exit_reason)).await;
                     unreachable!()
                 },
+            Event::Signal(Signal::Down(monitor_ref, actor_id, exit_reason)) => {
+                tracing::trace!(
+                    "unexpected down-signal [ref: {}, actor: {}, exit: {}]. Ignoring.",
+                    monitor_ref,
+                    actor_id,
+                    exit_reason.pp()
+                );
+            },
         }
     }
 }
 
+impl From<oneshot::error::RecvError> for SupervisorError {
+    fn from(e: oneshot::error::RecvError) -> Self {
+        Self::OneshotRx(e)
+    }
+}
+impl From<StartChildError> for SupervisorError {
+    fn from(e: StartChildError) -> Self {
+        Self::StartChildError(e)
+    }
+}
+impl From<tokio::time::error::Elapsed> for SupervisorError {
+    fn from(e: tokio::time::error::Elapsed) -> Self {
+        Self::Timeout(Arc::new(e))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -232,19 +256,3 @@ mod tests {
         assert!(system.all_actors().collect::<Vec<_>>().await.is_empty());
     }
 }
-
-impl From<oneshot::error::RecvError> for SupervisorError {
-    fn from(e: oneshot::error::RecvError) -> Self {
-        Self::OneshotRx(e)
-    }
-}
-impl From<StartChildError> for SupervisorError {
-    fn from(e: StartChildError) -> Self {
-        Self::StartChildError(e)
-    }
-}
-impl From<tokio::time::error::Elapsed> for SupervisorError {
-    fn from(e: tokio::time::error::Elapsed) -> Self {
-        Self::Timeout(Arc::new(e))
-    }
-}
@@ -1,8 +1,7 @@
 use std::sync::Arc;
 
-use agner_actors::{ActorID, Context};
+use agner_actors::{ActorID, Context, Received};
 use agner_init_ack::ContextInitAckExt;
-use agner_utils::future_timeout_ext::FutureTimeoutExt;
 
 use tokio::sync::{mpsc, oneshot, Mutex};
 
@@ -51,7 +50,7 @@ where
                     context.actor_id(),
                     timeout
                 );
-                if let Ok(event) = context.next_event().timeout(timeout).await {
+                if let Received::Event(event) = context.next_event_timeout(timeout).await {
                     tracing::trace!("[{}] received next-event", context.actor_id());
                     let _ = reply_to.send(event);
                 }
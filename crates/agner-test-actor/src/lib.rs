@@ -4,6 +4,9 @@ pub mod exited;
 pub mod query;
 pub mod registry;
 
+#[cfg(feature = "simulation")]
+pub mod simulation;
+
 pub use api::TestActor;
 pub use registry::TestActorRegistry;
 
@@ -0,0 +1,272 @@
+//! Deterministic simulation of a [`System`].
+//!
+//! Available with the `simulation` feature (it enables the `test-util` feature of tokio).
+//!
+//! A [`Simulation`] runs the actors on a single-threaded runtime with the paused clock, and
+//! polls them in the order chosen by a seeded pseudo-random scheduler: given the same seed, the
+//! actors' events (messages, links, exit-signals) interleave in the same way, so that a failing
+//! test can be replayed.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use agner_actors::{Context, Exit};
+//! use agner_test_actor::simulation::Simulation;
+//!
+//! async fn sleeper(_context: &mut Context<()>, delay: Duration) -> Exit {
+//!     tokio::time::sleep(delay).await;
+//!     Exit::normal()
+//! }
+//!
+//! let simulation = Simulation::new(42);
+//! let system = simulation.system(Default::default());
+//! simulation.run(async move {
+//!     let t0 = tokio::time::Instant::now();
+//!     let actor = system.spawn(sleeper, Duration::from_secs(3600), Default::default()).await.unwrap();
+//!     assert!(system.wait(actor).await.is_normal());
+//!     assert_eq!(t0.elapsed(), Duration::from_secs(3600));
+//! });
+//! ```
+//!
+//! The virtual time advances when every task of the simulation is idle (up to the nearest timer),
+//! or explicitly via [`tokio::time::advance`].
+//!
+//! The [monitor-refs](agner_actors::MonitorRef) and the [timer-refs](agner_actors::TimerRef) are
+//! numbered per system, so that they are reproduced along with the interleaving.
+//!
+//! The determinism holds as long as the simulated code does not depend on anything outside the
+//! simulation: the actors spawned with the default [spawner](agner_actors::Spawner), the blocking
+//! threads, the I/O, and the wall-clock time are beyond the scheduler's control.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Wake, Waker};
+
+use agner_actors::{Spawner, System, SystemConfig};
+use futures::future::BoxFuture;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tokio::runtime::Runtime;
+use tokio::sync::oneshot;
+
+/// The name of the environment variable, from which [`Simulation::from_env`] reads the seed.
+pub const SEED_ENV_VAR: &str = "AGNER_SIM_SEED";
+
+/// A single-threaded runtime with the paused clock and a seeded scheduler (see the
+/// [module-level documentation](crate::simulation)).
+pub struct Simulation {
+    seed: u64,
+    scheduler: Arc<Scheduler>,
+    runtime: Runtime,
+}
+
+/// The [`Spawner`] of the systems within a [`Simulation`].
+#[derive(Debug, Clone)]
+pub struct SimSpawner(Weak<Scheduler>);
+
+#[derive(Debug)]
+struct Scheduler(Mutex<State>);
+
+struct State {
+    rng: StdRng,
+    tasks: Vec<Task>,
+    ready: BTreeSet<usize>,
+    driver: Option<Waker>,
+}
+
+enum Task {
+    Idle(BoxFuture<'static, ()>),
+    Running,
+    Done,
+}
+
+struct TaskWaker {
+    task_id: usize,
+    scheduler: Weak<Scheduler>,
+}
+
+impl Simulation {
+    /// Create a simulation with the specified seed.
+    pub fn new(seed: u64) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .start_paused(true)
+            .build()
+            .expect("Failed to create tokio-runtime");
+        let state = State {
+            rng: StdRng::seed_from_u64(seed),
+            tasks: Default::default(),
+            ready: Default::default(),
+            driver: None,
+        };
+        Self { seed, scheduler: Arc::new(Scheduler(Mutex::new(state))), runtime }
+    }
+
+    /// Create a simulation with the seed taken from the environment variable
+    /// [`AGNER_SIM_SEED`](SEED_ENV_VAR), or with a random seed if the variable is not set.
+    pub fn from_env() -> Self {
+        let seed = match std::env::var(SEED_ENV_VAR) {
+            Ok(seed) => seed
+                .parse()
+                .unwrap_or_else(|_| panic!("{} is not a valid seed: {:?}", SEED_ENV_VAR, seed)),
+            Err(_) => rand::random(),
+        };
+        Self::new(seed)
+    }
+
+    /// The seed this simulation was created with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The [`Spawner`] to put into the [`SystemConfig`] of a simulated [`System`].
+    pub fn spawner(&self) -> SimSpawner {
+        SimSpawner(Arc::downgrade(&self.scheduler))
+    }
+
+    /// Create a [`System`] whose actors run within this simulation.
+    pub fn system(&self, config: SystemConfig) -> System {
+        let config = SystemConfig { spawner: Arc::new(self.spawner()), ..config };
+        let _guard = self.runtime.enter();
+        System::new(config)
+    }
+
+    /// Run the future as a task of the simulation, until it completes.
+    ///
+    /// The tasks spawned before, and while, the future runs, are run as well; those that remain
+    /// unfinished are resumed upon the next call to `run`.
+    ///
+    /// Should the future panic, the seed of the simulation is printed to stderr.
+    pub fn run<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let _report_seed = ReportSeedOnPanic(self.seed);
+
+        let (output_tx, mut output_rx) = oneshot::channel();
+        self.spawner().spawn(Box::pin(async move {
+            let _ = output_tx.send(future.await);
+        }));
+
+        self.runtime.block_on(futures::future::poll_fn(|cx| loop {
+            if let Ok(output) = output_rx.try_recv() {
+                break Poll::Ready(output)
+            }
+            if !self.scheduler.poll_next(cx) {
+                break Poll::Pending
+            }
+        }))
+    }
+}
+
+impl Spawner for SimSpawner {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        if let Some(scheduler) = self.0.upgrade() {
+            scheduler.spawn(task)
+        }
+    }
+}
+
+impl Scheduler {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        let mut state = self.0.lock().expect("Scheduler lock poisoned");
+        let task_id = state.tasks.len();
+        state.tasks.push(Task::Idle(Box::pin(tokio::task::unconstrained(task))));
+        state.schedule(task_id);
+    }
+
+    /// Poll a ready task chosen at random. Returns `false` if no task was ready.
+    fn poll_next(self: &Arc<Self>, cx: &mut Context<'_>) -> bool {
+        let (task_id, mut task) = {
+            let mut state = self.0.lock().expect("Scheduler lock poisoned");
+            state.driver = Some(cx.waker().to_owned());
+
+            if state.ready.is_empty() {
+                return false
+            }
+            let ready_count = state.ready.len();
+            let idx = state.rng.gen_range(0..ready_count);
+            let task_id = *state.ready.iter().nth(idx).expect("idx < ready.len()");
+            state.ready.remove(&task_id);
+
+            match std::mem::replace(&mut state.tasks[task_id], Task::Running) {
+                Task::Idle(task) => (task_id, task),
+                _ => unreachable!("a ready task is idle"),
+            }
+        };
+
+        let waker = Waker::from(Arc::new(TaskWaker { task_id, scheduler: Arc::downgrade(self) }));
+        let poll = task.as_mut().poll(&mut Context::from_waker(&waker));
+
+        let mut state = self.0.lock().expect("Scheduler lock poisoned");
+        if poll.is_ready() {
+            state.tasks[task_id] = Task::Done;
+            state.ready.remove(&task_id);
+        } else {
+            state.tasks[task_id] = Task::Idle(task);
+        }
+        true
+    }
+}
+
+impl State {
+    fn schedule(&mut self, task_id: usize) {
+        if matches!(self.tasks.get(task_id), Some(Task::Idle(_) | Task::Running)) {
+            self.ready.insert(task_id);
+            if let Some(driver) = self.driver.take() {
+                driver.wake();
+            }
+        }
+    }
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if let Some(scheduler) = self.scheduler.upgrade() {
+            scheduler.0.lock().expect("Scheduler lock poisoned").schedule(self.task_id);
+        }
+    }
+}
+
+impl Drop for Simulation {
+    fn drop(&mut self) {
+        let _guard = self.runtime.enter();
+        let tasks =
+            std::mem::take(&mut self.scheduler.0.lock().expect("Scheduler lock poisoned").tasks);
+        std::mem::drop(tasks);
+    }
+}
+
+struct ReportSeedOnPanic(u64);
+impl Drop for ReportSeedOnPanic {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            eprintln!("simulation failed [{}={}]", SEED_ENV_VAR, self.0);
+        }
+    }
+}
+
+impl fmt::Debug for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Simulation").field("seed", &self.seed).finish()
+    }
+}
+
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State")
+            .field("tasks", &self.tasks.len())
+            .field("ready", &self.ready)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use agner_actors::{ActorID, Context, Event, Exit, MonitorRef, Signal, TimerRef};
+use tokio::sync::oneshot;
+use tokio::time::Instant;
+
+use crate::simulation::Simulation;
+
+type Trace = Arc<Mutex<Vec<String>>>;
+
+async fn recorder(context: &mut Context<String>, trace: Trace) -> Exit {
+    context.trap_exit(true).await;
+    for _ in 0..4 {
+        let record = match context.next_event().await {
+            Event::Message(message) => message,
+            Event::Signal(Signal::Exit(_, exit_reason)) => format!("exit: {}", exit_reason),
+            Event::Signal(signal) => format!("{:?}", signal),
+        };
+        trace.lock().unwrap().push(record);
+    }
+    Exit::normal()
+}
+
+async fn sender(context: &mut Context<()>, to: ActorID) -> Exit {
+    for i in 0..3 {
+        context.system().send(to, format!("message #{}", i)).await;
+        tokio::task::yield_now().await;
+    }
+    Exit::normal()
+}
+
+async fn failing_linker(context: &mut Context<()>, to: ActorID) -> Exit {
+    context.link(to).await;
+    tokio::task::yield_now().await;
+    Exit::from_message("failure")
+}
+
+fn run_scenario(seed: u64) -> Vec<String> {
+    let simulation = Simulation::new(seed);
+    let system = simulation.system(Default::default());
+    simulation.run(async move {
+        let trace = Trace::default();
+        let recorder = system.spawn(recorder, trace.to_owned(), Default::default()).await.unwrap();
+        system.spawn(sender, recorder, Default::default()).await.unwrap();
+        system.spawn(failing_linker, recorder, Default::default()).await.unwrap();
+
+        let exit_reason = system.wait(recorder).await;
+        let mut trace = trace.lock().unwrap().to_owned();
+        trace.push(format!("recorder exited: {}", exit_reason));
+        trace
+    })
+}
+
+#[test]
+fn same_seed_same_interleaving() {
+    let traces = (0..32).map(run_scenario).collect::<Vec<_>>();
+
+    for (seed, trace) in traces.iter().enumerate() {
+        assert_eq!(&run_scenario(seed as u64), trace, "seed: {}", seed);
+    }
+    assert!(traces.iter().collect::<HashSet<_>>().len() > 1);
+}
+
+#[test]
+fn timers_run_in_virtual_time() {
+    async fn delayed_echo(context: &mut Context<ActorID>, delay: Duration) -> Exit {
+        let reply_to = context.next_message().await;
+        context.system().send_after(reply_to, delay, ()).await;
+        Exit::normal()
+    }
+    async fn waiter(context: &mut Context<()>, _args: ()) -> Exit {
+        context.next_message().await;
+        Exit::normal()
+    }
+
+    const DAY: Duration = Duration::from_secs(24 * 3600);
+
+    let simulation = Simulation::new(1);
+    let system = simulation.system(Default::default());
+    simulation.run(async move {
+        let t0 = Instant::now();
+        let echo = system.spawn(delayed_echo, DAY, Default::default()).await.unwrap();
+        let waiter = system.spawn(waiter, (), Default::default()).await.unwrap();
+        system.send(echo, waiter).await;
+
+        assert!(system.wait(waiter).await.is_normal());
+        assert_eq!(t0.elapsed(), DAY);
+
+        tokio::time::advance(DAY).await;
+        assert_eq!(t0.elapsed(), DAY * 2);
+    });
+}
+
+#[test]
+fn refs_are_reproduced() {
+    async fn issuer(
+        context: &mut Context<()>,
+        report_to: oneshot::Sender<(MonitorRef, TimerRef)>,
+    ) -> Exit {
+        let monitor_ref = context.monitor(context.actor_id()).await;
+        let timer_ref = context.send_after(Duration::from_secs(1), ()).await;
+        let _ = report_to.send((monitor_ref, timer_ref));
+        Exit::normal()
+    }
+
+    let refs = || {
+        let simulation = Simulation::new(1);
+        let system = simulation.system(Default::default());
+        simulation.run(async move {
+            let (tx, rx) = oneshot::channel();
+            system.spawn(issuer, tx, Default::default()).await.unwrap();
+            rx.await.unwrap()
+        })
+    };
+
+    assert_eq!(refs(), refs());
+}
@@ -9,8 +9,14 @@ use futures::lock::BiLock;
 mod tests;
 
 pub fn channel<T>(max_len: usize) -> (Sender<T>, Receiver<T>) {
-    let inner =
-        Inner { queue: Default::default(), max_len, sender_waker: None, receiver_waker: None };
+    let inner = Inner {
+        queue: Default::default(),
+        max_len,
+        evicted: 0,
+        received: 0,
+        sender_waker: None,
+        receiver_waker: None,
+    };
 
     let (sender, receiver) = BiLock::new(inner);
     (Sender(sender), Receiver(receiver))
@@ -30,6 +36,19 @@ where
         Receive { lock: &self.0, should_block }
     }
 
+    /// Receive the first item satisfying the predicate, leaving the others in the queue in their
+    /// original order.
+    pub fn recv_matching<'a, F>(
+        &'a mut self,
+        predicate: F,
+        should_block: bool,
+    ) -> impl Future<Output = Option<T>> + 'a
+    where
+        F: FnMut(&T) -> bool + Unpin + 'a,
+    {
+        ReceiveMatching { lock: &self.0, should_block, predicate, scanned: 0, evicted: None }
+    }
+
     pub async fn len(&self) -> (usize, usize) {
         let locked = self.0.lock().await;
         (locked.queue.len(), locked.max_len)
@@ -48,16 +67,45 @@ where
         Send { lock: &self.0, should_block, item: Some(item) }
     }
 
+    /// Put the item into the queue. Should the queue be full, the oldest item is evicted from the
+    /// queue and returned.
+    pub async fn send_evicting(&mut self, item: T) -> Option<T> {
+        let mut locked = self.0.lock().await;
+        let evicted = if locked.queue.len() >= locked.max_len {
+            locked.evicted += 1;
+            locked.queue.pop_front()
+        } else {
+            None
+        };
+        locked.queue.push_back(item);
+        if let Some(waker) = locked.receiver_waker.take() {
+            waker.wake();
+        }
+        evicted
+    }
+
+    /// Wait until there is room in the queue for at least one item.
+    pub fn ready(&mut self) -> impl Future<Output = ()> + '_ {
+        Ready { lock: &self.0 }
+    }
+
     pub async fn len(&self) -> (usize, usize) {
         let locked = self.0.lock().await;
         (locked.queue.len(), locked.max_len)
     }
+
+    /// The number of items taken from the queue by the receiver so far.
+    pub async fn received_count(&self) -> usize {
+        self.0.lock().await.received
+    }
 }
 
 #[derive(Debug)]
 struct Inner<T> {
     queue: VecDeque<T>,
     max_len: usize,
+    evicted: usize,
+    received: usize,
     sender_waker: Option<Waker>,
     receiver_waker: Option<Waker>,
 }
@@ -68,6 +116,19 @@ struct Receive<'a, T> {
     should_block: bool,
 }
 
+#[pin_project::pin_project]
+struct ReceiveMatching<'a, T, F> {
+    lock: &'a BiLock<Inner<T>>,
+    should_block: bool,
+    predicate: F,
+    scanned: usize,
+    evicted: Option<usize>,
+}
+
+struct Ready<'a, T> {
+    lock: &'a BiLock<Inner<T>>,
+}
+
 #[pin_project::pin_project]
 struct Send<'a, T> {
     lock: &'a BiLock<Inner<T>>,
@@ -89,6 +150,52 @@ where
 
         match (locked.queue.pop_front(), this.should_block) {
             (Some(item), _) => {
+                locked.received += 1;
+                if let Some(waker) = locked.sender_waker.take() {
+                    waker.wake();
+                }
+                Poll::Ready(Some(item))
+            },
+            (None, false) => Poll::Ready(None),
+            (None, true) => {
+                let should_be_none = locked.receiver_waker.replace(cx.waker().to_owned());
+                assert!(should_be_none.is_none());
+                Poll::Pending
+            },
+        }
+    }
+}
+
+impl<'a, T, F> Future for ReceiveMatching<'a, T, F>
+where
+    T: Unpin,
+    F: FnMut(&T) -> bool + Unpin,
+{
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let mut locked = futures::ready!(this.lock.poll_lock(cx));
+        let _ = locked.receiver_waker.take();
+
+        // the items are only removed by the receiver or evicted from the front by the sender, so
+        // the ones that have been already scanned by this future need not be checked again.
+        let evicted_since = this.evicted.map(|e| locked.evicted - e).unwrap_or_default();
+        *this.evicted = Some(locked.evicted);
+        let skip = this.scanned.saturating_sub(evicted_since);
+        let found = locked
+            .queue
+            .iter()
+            .skip(skip)
+            .position(this.predicate)
+            .map(|offset| skip + offset);
+        *this.scanned = locked.queue.len();
+
+        match (found, this.should_block) {
+            (Some(idx), _) => {
+                let item = locked.queue.remove(idx).expect("idx out of bounds");
+                locked.received += 1;
                 if let Some(waker) = locked.sender_waker.take() {
                     waker.wake();
                 }
@@ -137,3 +244,20 @@ where
         }
     }
 }
+
+impl<'a, T> Future for Ready<'a, T>
+where
+    T: Unpin,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut locked = futures::ready!(self.lock.poll_lock(cx));
+        if locked.queue.len() < locked.max_len {
+            Poll::Ready(())
+        } else {
+            locked.sender_waker = Some(cx.waker().to_owned());
+            Poll::Pending
+        }
+    }
+}
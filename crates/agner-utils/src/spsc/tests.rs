@@ -37,3 +37,84 @@ async fn blocking_basic() {
 
     assert_eq!(future::join(producer, consumer).await, ((), ()));
 }
+
+#[tokio::test]
+async fn recv_matching_keeps_the_order_of_the_rest() {
+    let (mut tx, mut rx) = channel::<usize>(5);
+    for i in 1..=5 {
+        assert!(tx.send(i, false).await.is_ok());
+    }
+
+    assert_eq!(rx.recv_matching(|i| *i == 3, false).await, Some(3));
+    assert_eq!(rx.recv_matching(|i| *i > 10, false).await, None);
+    assert!(tx.send(6, false).await.is_ok());
+    assert_eq!(tx.send(7, false).await, Err(7));
+
+    assert_eq!(rx.recv_matching(|i| i % 2 == 0, false).await, Some(2));
+    for i in [1, 4, 5, 6] {
+        assert_eq!(rx.recv(false).await, Some(i));
+    }
+    assert_eq!(rx.recv(false).await, None);
+}
+
+#[tokio::test]
+async fn recv_matching_blocks_until_a_match_arrives() {
+    let (mut tx, mut rx) = channel::<usize>(10);
+    let producer = async move {
+        for i in 1..10 {
+            assert!(tx.send(i, true).await.is_ok());
+            crate::async_yield::async_yield().await;
+        }
+        tx
+    };
+    let consumer = async move {
+        assert_eq!(rx.recv_matching(|i| *i == 9, true).await, Some(9));
+        for i in 1..9 {
+            assert_eq!(rx.recv(false).await, Some(i));
+        }
+    };
+
+    let (_tx, ()) = future::join(producer, consumer).await;
+}
+
+#[tokio::test]
+async fn send_evicting_drops_the_oldest() {
+    let (mut tx, mut rx) = channel::<usize>(3);
+    for i in 1..=3 {
+        assert_eq!(tx.send_evicting(i).await, None);
+    }
+    assert_eq!(tx.send_evicting(4).await, Some(1));
+    assert_eq!(tx.send_evicting(5).await, Some(2));
+
+    for i in [3, 4, 5] {
+        assert_eq!(rx.recv(false).await, Some(i));
+    }
+    assert_eq!(rx.recv(false).await, None);
+    assert_eq!(tx.received_count().await, 3);
+}
+
+#[tokio::test]
+async fn recv_matching_sees_items_pushed_after_eviction() {
+    let (mut tx, mut rx) = channel::<usize>(2);
+    assert!(tx.send(1, false).await.is_ok());
+    assert!(tx.send(2, false).await.is_ok());
+
+    let mut receiving = Box::pin(rx.recv_matching(|i| *i == 3, true));
+    assert!(futures::poll!(receiving.as_mut()).is_pending());
+
+    assert_eq!(tx.send_evicting(3).await, Some(1));
+    assert_eq!(receiving.await, Some(3));
+}
+
+#[tokio::test]
+async fn ready_waits_for_room() {
+    let (mut tx, mut rx) = channel::<usize>(1);
+    assert!(futures::poll!(tx.ready()).is_ready());
+    assert!(tx.send(1, false).await.is_ok());
+
+    let consumer = async move {
+        tokio::task::yield_now().await;
+        assert_eq!(rx.recv(false).await, Some(1));
+    };
+    future::join(tx.ready(), consumer).await;
+}
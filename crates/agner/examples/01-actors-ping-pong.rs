@@ -83,7 +83,7 @@ mod tournament {
                 break players.pop()
             }
             tour_id += 1;
-            run_tour(&system, tour_id, &mut players).await;
+            run_tour(system, tour_id, &mut players).await;
         }
     }
 
@@ -114,7 +114,7 @@ mod tournament {
 
         let mut futures_unordered = FuturesUnordered::new();
         for (match_id, (left, right)) in matches.into_iter().enumerate() {
-            futures_unordered.push(run_match(&system, tour_id, match_id, left, right));
+            futures_unordered.push(run_match(system, tour_id, match_id, left, right));
         }
         while let Some(match_winner) = futures_unordered.next().await {
             players.push(match_winner);
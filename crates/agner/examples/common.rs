@@ -8,7 +8,7 @@ use agner_actors::{System, SystemConfig};
 pub fn system(max_actors: usize) -> System {
     let exit_handler = Arc::new(agner::actors::exit_handlers::LogExitHandler);
 
-    System::new(SystemConfig { max_actors, exit_handler, ..Default::default() })
+    System::new(SystemConfig { max_actors: Some(max_actors), exit_handler, ..Default::default() })
 }
 
 pub fn run<F>(multi_thread: bool, f: F) -> F::Output
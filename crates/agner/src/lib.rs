@@ -28,8 +28,7 @@
 //! - the behaviour function.
 //!
 //! In order to implement an actor one should define an async function that
-//! - returns a value for which the trait [`Into<Exit>`](crate::actors::Exit) is
-//! defined
+//! - returns a value for which the trait [`Into<Exit>`](crate::actors::Exit) is defined
 //! - and accepts two arguments:
 //!     - a mutable reference to [`Context<Message>`](crate::actors::Context);
 //!     - `Argument`.
@@ -161,6 +160,16 @@
 //! TBD:
 //! - [mixed supervisor](crate::sup::mixed)
 //!
+//! # Server Behaviour
+//!
+//! TBD:
+//! - [server](crate::server)
+//!
+//! # State Machine Behaviour
+//!
+//! TBD:
+//! - [statem](crate::statem)
+//!
 //! # Introspection
 //!
 //! TBD:
@@ -184,14 +193,14 @@ pub use agner_reg as reg;
 #[cfg(feature = "sup")]
 pub use agner_sup as sup;
 
+#[cfg(feature = "server")]
+pub use agner_server as server;
+
+#[cfg(feature = "statem")]
+pub use agner_statem as statem;
+
 #[cfg(feature = "helm")]
 pub use agner_helm as helm;
 
 #[cfg(feature = "test-actor")]
 pub use agner_test_actor as test_actor;
-
-#[cfg(feature = "log")]
-pub use agner_tracing::log;
-
-#[cfg(feature = "log")]
-pub use agner_log as log;
@@ -94,8 +94,7 @@ agner_test!(linked_actors_terminates_together, async {
             .await
             .unwrap()
             .links
-            .as_ref()
-            .into_iter()
+            .iter()
             .copied()
             .collect::<HashSet<_>>(),
         [a1.actor_id(), a3.actor_id()].into_iter().collect::<HashSet<_>>()
@@ -140,8 +139,7 @@ agner_test!(link_does_not_trigger_on_exit_normal, async {
             .await
             .unwrap()
             .links
-            .as_ref()
-            .into_iter()
+            .iter()
             .copied()
             .collect::<HashSet<_>>(),
         [a1.actor_id(), a3.actor_id()].into_iter().collect::<HashSet<_>>()
@@ -186,8 +184,7 @@ agner_test!(unlink_works, async {
             .await
             .unwrap()
             .links
-            .as_ref()
-            .into_iter()
+            .iter()
             .copied()
             .collect::<HashSet<_>>(),
         [a1.actor_id(), a3.actor_id()].into_iter().collect::<HashSet<_>>()
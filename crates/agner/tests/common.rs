@@ -37,7 +37,7 @@ macro_rules! agner_test {
 pub fn system(max_actors: usize) -> System {
     let exit_handler = Arc::new(agner::actors::exit_handlers::LogExitHandler);
 
-    System::new(SystemConfig { max_actors, exit_handler, ..Default::default() })
+    System::new(SystemConfig { max_actors: Some(max_actors), exit_handler, ..Default::default() })
 }
 
 #[allow(unused)]